@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{srgb, srgba};
+
+/// any color paired with a straight (not premultiplied) alpha. converting with [Alpha::convert] changes the space of the color and leaves `a` untouched, so one type covers translucent colors in every space
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Alpha<C> {
+    pub color: C,
+    pub a: f32,
+}
+
+impl<C> Alpha<C> {
+    /// pairs `color` with the straight alpha `a`
+    pub fn new(color: C, a: f32) -> Self {
+        Self { color, a }
+    }
+
+    /// converts the color into another space, carrying the alpha over exactly
+    ///
+    /// a blanket `From` impl would conflict with the reflexive `From<T> for T`, so this is a method
+    pub fn convert<D: From<C>>(self) -> Alpha<D> {
+        Alpha {
+            color: self.color.into(),
+            a: self.a,
+        }
+    }
+}
+
+impl From<srgba> for Alpha<srgb> {
+    fn from(value: srgba) -> Self {
+        Self::new(value.rgb(), value.a)
+    }
+}
+
+impl From<Alpha<srgb>> for srgba {
+    fn from(Alpha { color, a }: Alpha<srgb>) -> Self {
+        srgba::with_alpha(color, a)
+    }
+}
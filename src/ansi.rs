@@ -0,0 +1,11 @@
+use crate::{bytes, srgb};
+
+/// a single 24-bit ansi escape that sets both the foreground and background color, about half the bytes of two separate escapes. channels are clamped to [0, 1] before quantizing
+///
+/// the colors stay set until the terminal sees a reset, so append `"\x1b[0m"` once you're done drawing
+pub fn ansi_pair(fg: srgb, bg: srgb) -> String {
+    let [r, g, b] = [fg.r, fg.g, fg.b].map(bytes::to_u8);
+    let [bg_r, bg_g, bg_b] = [bg.r, bg.g, bg.b].map(bytes::to_u8);
+
+    format!("\x1b[38;2;{r};{g};{b};48;2;{bg_r};{bg_g};{bg_b}m")
+}
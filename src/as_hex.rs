@@ -0,0 +1,15 @@
+//! serializes an [srgb] as a `#rrggbb` hex string instead of a struct, for human friendly config files. use it with `#[serde(with = "tinycolors::as_hex")]`
+
+use serde::{Deserialize, Deserializer, Serializer, de};
+
+use crate::srgb;
+
+pub fn serialize<S: Serializer>(color: &srgb, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&color.to_hex())
+}
+
+/// accepts anything [srgb::from_hex] does
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<srgb, D::Error> {
+    let hex = String::deserialize(deserializer)?;
+    srgb::from_hex(&hex).map_err(|err| de::Error::custom(format!("{err}: {hex:?}")))
+}
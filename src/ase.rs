@@ -0,0 +1,115 @@
+use std::fmt;
+
+use crate::{cielab, cmyk, srgb};
+
+/// the block type of a color entry. group start (0xc001) and end (0xc002) blocks are skipped
+const COLOR_BLOCK: u16 = 0x0001;
+
+/// the ways an adobe swatch exchange file can fail to parse
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AseError {
+    /// the file didn't start with `ASEF`
+    InvalidSignature,
+    /// the file ended in the middle of a block
+    Truncated,
+    /// a swatch name wasn't valid utf-16
+    InvalidName,
+    /// a swatch used a color model other than rgb, cmyk, gray or lab
+    UnsupportedModel,
+}
+
+impl fmt::Display for AseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AseError::InvalidSignature => write!(f, "not an adobe swatch exchange file"),
+            AseError::Truncated => write!(f, "swatch file ended unexpectedly"),
+            AseError::InvalidName => write!(f, "swatch name isn't valid utf-16"),
+            AseError::UnsupportedModel => write!(f, "unsupported swatch color model"),
+        }
+    }
+}
+
+impl std::error::Error for AseError {}
+
+/// parses an adobe swatch exchange (.ase) file, the binary palette format illustrator and photoshop export, into its swatches and their names in file order. groups are flattened
+///
+/// cmyk swatches go through the naive [cmyk] conversion and lab swatches are read as d50 [cielab], so neither matches what adobe's color management would show exactly
+pub fn parse_ase(bytes: &[u8]) -> Result<Vec<(String, srgb)>, AseError> {
+    let mut reader = Reader { bytes };
+    if reader.take(4)? != b"ASEF" {
+        return Err(AseError::InvalidSignature);
+    }
+
+    // the version, 1.0 in every file in the wild
+    reader.take(4)?;
+    let blocks = reader.u32()?;
+
+    let mut swatches = Vec::new();
+    for _ in 0..blocks {
+        let kind = reader.u16()?;
+        let length = reader.u32()? as usize;
+        let mut block = Reader {
+            bytes: reader.take(length)?,
+        };
+
+        if kind == COLOR_BLOCK {
+            swatches.push(block.swatch()?);
+        }
+    }
+
+    Ok(swatches)
+}
+
+/// reads big endian values off the front of a byte slice
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], AseError> {
+        if self.bytes.len() < n {
+            return Err(AseError::Truncated);
+        }
+
+        let (taken, rest) = self.bytes.split_at(n);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn u16(&mut self) -> Result<u16, AseError> {
+        let b = self.take(2)?;
+        Ok(u16::from_be_bytes([b[0], b[1]]))
+    }
+
+    fn u32(&mut self) -> Result<u32, AseError> {
+        let b = self.take(4)?;
+        Ok(u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    fn f32(&mut self) -> Result<f32, AseError> {
+        Ok(f32::from_bits(self.u32()?))
+    }
+
+    /// a color block: the name as a length prefixed, null terminated utf-16 string, the model, then its channels
+    fn swatch(&mut self) -> Result<(String, srgb), AseError> {
+        let length = self.u16()? as usize;
+        let units = (0..length)
+            .map(|_| self.u16())
+            .collect::<Result<Vec<_>, _>>()?;
+        let units = units.strip_suffix(&[0]).unwrap_or(&units);
+        let name = String::from_utf16(units).map_err(|_| AseError::InvalidName)?;
+
+        let color: srgb = match self.take(4)? {
+            b"RGB " => [self.f32()?, self.f32()?, self.f32()?].into(),
+            b"CMYK" => cmyk::from([self.f32()?, self.f32()?, self.f32()?, self.f32()?]).into(),
+            b"Gray" => srgb::from([self.f32()?; 3]),
+            b"LAB " => {
+                let (l, a, b) = (self.f32()?, self.f32()?, self.f32()?);
+                cielab { l: l * 100.0, a, b }.d50_to_xyz().into()
+            }
+            _ => return Err(AseError::UnsupportedModel),
+        };
+
+        Ok((name, color))
+    }
+}
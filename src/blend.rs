@@ -0,0 +1,92 @@
+use crate::srgb;
+
+/// how [srgb::blend] combines a source color with the backdrop it's drawn over. the formulas are the ones from the pdf spec (ISO 32000), which is what photoshop and css follow, so they operate on the srgb values directly
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BlendMode {
+    /// the source replaces the backdrop
+    #[default]
+    Normal,
+    Multiply,
+    Screen,
+    /// the absolute difference of each channel
+    Difference,
+    /// like difference, but with lower contrast
+    Exclusion,
+    /// the hue of the source with the saturation and luminosity of the backdrop
+    Hue,
+    /// the saturation of the source with the hue and luminosity of the backdrop
+    Saturation,
+    /// the hue and saturation of the source with the luminosity of the backdrop
+    Color,
+    /// the luminosity of the source with the hue and saturation of the backdrop
+    Luminosity,
+}
+
+impl srgb {
+    /// blends `source` over this color
+    pub fn blend(self, source: srgb, mode: BlendMode) -> srgb {
+        let (cb, cs): ([f32; 3], [f32; 3]) = (self.into(), source.into());
+
+        let separable = |f: fn(f32, f32) -> f32| -> [f32; 3] {
+            [f(cb[0], cs[0]), f(cb[1], cs[1]), f(cb[2], cs[2])]
+        };
+
+        match mode {
+            BlendMode::Normal => cs,
+            BlendMode::Multiply => separable(|b, s| b * s),
+            BlendMode::Screen => separable(|b, s| b + s - b * s),
+            BlendMode::Difference => separable(|b, s| (b - s).abs()),
+            BlendMode::Exclusion => separable(|b, s| b + s - 2.0 * b * s),
+            BlendMode::Hue => set_lum(set_sat(cs, sat(cb)), lum(cb)),
+            BlendMode::Saturation => set_lum(set_sat(cb, sat(cs)), lum(cb)),
+            BlendMode::Color => set_lum(cs, lum(cb)),
+            BlendMode::Luminosity => set_lum(cb, lum(cs)),
+        }
+        .into()
+    }
+}
+
+// the helpers for the non-separable modes, named after the pdf spec
+
+fn lum(c: [f32; 3]) -> f32 {
+    0.3 * c[0] + 0.59 * c[1] + 0.11 * c[2]
+}
+
+fn sat(c: [f32; 3]) -> f32 {
+    c[0].max(c[1]).max(c[2]) - c[0].min(c[1]).min(c[2])
+}
+
+/// pulls out of range channels back in towards the luminosity, which stays the same
+fn clip_color(c: [f32; 3]) -> [f32; 3] {
+    let l = lum(c);
+    let n = c[0].min(c[1]).min(c[2]);
+    let x = c[0].max(c[1]).max(c[2]);
+
+    // the l > n and x > l checks skip a gray, which has no direction to clip in and would divide 0 by 0
+    let mut c = c;
+    if n < 0.0 && l > n {
+        c = c.map(|c| l + (c - l) * l / (l - n));
+    }
+    if x > 1.0 && x > l {
+        c = c.map(|c| l + (c - l) * (1.0 - l) / (x - l));
+    }
+    c
+}
+
+fn set_lum(c: [f32; 3], l: f32) -> [f32; 3] {
+    let d = l - lum(c);
+    clip_color(c.map(|c| c + d))
+}
+
+fn set_sat(c: [f32; 3], s: f32) -> [f32; 3] {
+    let mut order = [0, 1, 2];
+    order.sort_by(|&a, &b| c[a].total_cmp(&c[b]));
+    let [min, mid, max] = order;
+
+    let mut result = [0.0; 3];
+    if c[max] > c[min] {
+        result[mid] = (c[mid] - c[min]) * s / (c[max] - c[min]);
+        result[max] = s;
+    }
+    result
+}
@@ -0,0 +1,92 @@
+use crate::{palette::SplitMix64, srgb, srgba};
+
+/// how [srgb::to_u8_with] turns a channel into a byte
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RoundMode {
+    /// to the nearest byte, what every other byte conversion in this crate does
+    #[default]
+    Nearest,
+    /// down to the byte below
+    Floor,
+    /// up to the byte above
+    Ceil,
+    /// up or down at random, with the chance of rounding up equal to the fractional part. the bytes average out to the exact value, which breaks up the banding 8-bit gradients get. the noise comes from the seed alone, so the same seed always rounds the same way. give every pixel its own seed, like its index, for the noise to average out
+    Stochastic(u64),
+}
+
+/// quantizes a channel to a byte, clamping it to [0, 1] and rounding to the nearest value
+pub(crate) fn to_u8(c: f32) -> u8 {
+    (c.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+pub(crate) const fn from_u8(c: u8) -> f32 {
+    c as f32 / 255.0
+}
+
+/// quantizes a channel to an unsigned field with `bits` bits, clamping it to [0, 1] and rounding to the nearest value
+fn to_bits(c: f32, bits: u32) -> u16 {
+    let max = ((1 << bits) - 1) as f32;
+    (c.clamp(0.0, 1.0) * max).round() as u16
+}
+
+fn from_bits(c: u16, bits: u32) -> f32 {
+    c as f32 / ((1 << bits) - 1) as f32
+}
+
+/// unpacks an rgb565 color, red in the top 5 bits, green in the middle 6, and blue in the low 5
+impl From<u16> for srgb {
+    fn from(value: u16) -> Self {
+        srgb {
+            r: from_bits(value >> 11, 5),
+            g: from_bits((value >> 5) & 0x3f, 6),
+            b: from_bits(value & 0x1f, 5),
+        }
+    }
+}
+
+impl srgb {
+    /// the color as bytes, rounded with `mode`. channels are clamped to [0, 1] first
+    pub fn to_u8_with(self, mode: RoundMode) -> [u8; 3] {
+        let mut rng = SplitMix64(match mode {
+            RoundMode::Stochastic(seed) => seed,
+            _ => 0,
+        });
+
+        [self.r, self.g, self.b].map(|c| {
+            let x = c.clamp(0.0, 1.0) * 255.0;
+            let rounded = match mode {
+                RoundMode::Nearest => x.round(),
+                RoundMode::Floor => x.floor(),
+                RoundMode::Ceil => x.ceil(),
+                RoundMode::Stochastic(_) => (x + rng.next_f32()).floor(),
+            };
+            rounded.min(255.0) as u8
+        })
+    }
+
+    /// packs the color as rgb565, the 16-bit format common on small displays. channels are clamped to [0, 1] before quantizing
+    pub fn to_rgb565(&self) -> u16 {
+        (to_bits(self.r, 5) << 11) | (to_bits(self.g, 6) << 5) | to_bits(self.b, 5)
+    }
+}
+
+impl srgb {
+    /// snaps each channel to the nearest of the six web safe levels, 0.0, 0.2, 0.4, 0.6, 0.8, and 1.0, in srgb where the palette is defined. channels are clamped to [0, 1] first, and a channel exactly between two levels goes to the lighter one
+    pub fn to_web_safe(&self) -> srgb {
+        [self.r, self.g, self.b]
+            .map(|c| (c.clamp(0.0, 1.0) * 5.0).round() / 5.0)
+            .into()
+    }
+}
+
+impl srgba {
+    /// the color as bytes in r, g, b, a order. channels are clamped to [0, 1] before quantizing
+    pub fn to_rgba_bytes(&self) -> [u8; 4] {
+        [self.r, self.g, self.b, self.a].map(to_u8)
+    }
+
+    /// the color as bytes in b, g, r, a order, the layout many surfaces and windowing apis want. channels are clamped to [0, 1] before quantizing
+    pub fn to_bgra_bytes(&self) -> [u8; 4] {
+        [self.b, self.g, self.r, self.a].map(to_u8)
+    }
+}
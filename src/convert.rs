@@ -0,0 +1,34 @@
+use crate::{cielab, cielch, hsi, hsl, hsv, okhsl, okhsv, oklab, oklch, rgb, srgb, xyy, xyz};
+
+/// inherent `to_*` methods on every color struct for every other one, so they show up in autocomplete and work without [Color](crate::Color) in scope. the trait has the same methods for the spaces it requires, for generic code
+macro_rules! conversions {
+    ($($color:ident),* $(,)?) => {$(
+        conversions!(@methods $color;
+            to_srgb: srgb,
+            to_rgb: rgb,
+            to_oklab: oklab,
+            to_okhsl: okhsl,
+            to_okhsv: okhsv,
+            to_hsl: hsl,
+            to_hsv: hsv,
+            to_xyz: xyz,
+            to_cielab: cielab,
+            to_cielch: cielch,
+            to_hsi: hsi,
+            to_oklch: oklch,
+            to_xyy: xyy,
+        );
+    )*};
+    (@methods $color:ident; $($method:ident: $target:ident),* $(,)?) => {
+        impl $color {$(
+            #[doc = concat!("shorthand for `", stringify!($target), "::from(self)`, which reads better in method chains")]
+            pub fn $method(self) -> $target {
+                self.into()
+            }
+        )*}
+    };
+}
+
+conversions!(
+    srgb, rgb, oklab, okhsl, okhsv, hsl, hsv, xyz, cielab, cielch, hsi, oklch, xyy,
+);
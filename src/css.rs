@@ -0,0 +1,170 @@
+use crate::{Alpha, ColorError, oklab, rgb, srgb, xyz};
+
+/// a color parsed from the css `color()` function, in the space it was written in
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CssColor {
+    /// `color(srgb r g b)`
+    Srgb(srgb),
+    /// `color(srgb-linear r g b)`
+    SrgbLinear(rgb),
+    /// `color(oklab l a b)`
+    Oklab(oklab),
+    /// `color(xyz x y z)` or `color(xyz-d65 x y z)`. `color(xyz-d50 x y z)` is adapted to d65 with [xyz::from_d50]
+    Xyz(xyz),
+}
+
+impl CssColor {
+    /// converts the color to srgb whatever space it was written in
+    pub fn to_srgb(&self) -> srgb {
+        match *self {
+            CssColor::Srgb(color) => color,
+            CssColor::SrgbLinear(color) => color.into(),
+            CssColor::Oklab(color) => color.into(),
+            CssColor::Xyz(color) => color.into(),
+        }
+    }
+}
+
+impl srgb {
+    /// formats the color as a css `color(srgb r g b)` function, with up to 4 decimals per channel
+    pub fn to_css_color(&self) -> String {
+        format!("color(srgb {})", channels([self.r, self.g, self.b]))
+    }
+}
+
+impl rgb {
+    /// formats the color as a css `color(srgb-linear r g b)` function, with up to 4 decimals per channel
+    pub fn to_css_color(&self) -> String {
+        format!("color(srgb-linear {})", channels([self.r, self.g, self.b]))
+    }
+}
+
+impl oklab {
+    /// formats the color as a css `oklab(l a b)` function, with up to 4 decimals per channel
+    pub fn to_css_color(&self) -> String {
+        format!("oklab({})", channels([self.l, self.a, self.b]))
+    }
+}
+
+impl xyz {
+    /// formats the color as a css `color(xyz-d65 x y z)` function, with up to 4 decimals per channel
+    pub fn to_css_color(&self) -> String {
+        format!("color(xyz-d65 {})", channels([self.x, self.y, self.z]))
+    }
+}
+
+impl CssColor {
+    /// formats the color in the space it was parsed in, see [srgb::to_css_color]
+    pub fn to_css_color(&self) -> String {
+        match self {
+            CssColor::Srgb(color) => color.to_css_color(),
+            CssColor::SrgbLinear(color) => color.to_css_color(),
+            CssColor::Oklab(color) => color.to_css_color(),
+            CssColor::Xyz(color) => color.to_css_color(),
+        }
+    }
+}
+
+impl Alpha<CssColor> {
+    /// formats the color like [CssColor::to_css_color], adding the alpha after a slash unless it's 1.0, so it parses back with [parse_css_color]
+    pub fn to_css_color(&self) -> String {
+        let color = self.color.to_css_color();
+        match color.strip_suffix(')') {
+            Some(function) if self.a != 1.0 => format!("{function} / {})", number_to_css(self.a)),
+            _ => color,
+        }
+    }
+}
+
+/// parses the css color 4 `color()` function, like `color(srgb 1 0.5 0.25)` or `color(oklab 0.7 0.1 -0.05 / 50%)`, and the `oklab()` function that [oklab::to_css_color] writes
+///
+/// the spaces are srgb, srgb-linear, oklab, xyz, xyz-d65, and xyz-d50. channels are numbers, percentages, or `none` for 0, and the alpha after the slash defaults to 1.0. display-p3, rec2020, and the other wide gamut spaces have no type in this crate yet, so they're reported as [ColorError::UnsupportedSpace]
+pub fn parse_css_color(css: &str) -> Result<Alpha<CssColor>, ColorError> {
+    let body = css.trim().strip_suffix(')').ok_or(ColorError::InvalidCss)?;
+    let (space, inner) = match body.strip_prefix("oklab(") {
+        Some(inner) => (Some("oklab"), inner),
+        None => (
+            None,
+            body.strip_prefix("color(").ok_or(ColorError::InvalidCss)?,
+        ),
+    };
+
+    let (channels, alpha) = match inner.split_once('/') {
+        Some((channels, alpha)) => (channels, Some(alpha)),
+        None => (inner, None),
+    };
+
+    let mut words = channels.split_whitespace();
+    let space = match space {
+        Some(space) => space,
+        None => words.next().ok_or(ColorError::InvalidCss)?,
+    };
+    // what 100% stands for in each channel
+    let percent_of = match space {
+        "srgb" | "srgb-linear" | "xyz" | "xyz-d65" | "xyz-d50" => [1.0; 3],
+        "oklab" => [1.0, 0.4, 0.4],
+        "display-p3" | "a98-rgb" | "prophoto-rgb" | "rec2020" => {
+            return Err(ColorError::UnsupportedSpace);
+        }
+        _ => return Err(ColorError::InvalidCss),
+    };
+
+    let values: Vec<&str> = words.collect();
+    let [a, b, c] = values[..] else {
+        return Err(ColorError::InvalidCss);
+    };
+    let channels = [
+        number(a, percent_of[0])?,
+        number(b, percent_of[1])?,
+        number(c, percent_of[2])?,
+    ];
+
+    let alpha = match alpha {
+        None => 1.0,
+        Some(alpha) => match alpha.split_whitespace().collect::<Vec<_>>()[..] {
+            [alpha] => number(alpha, 1.0)?,
+            _ => return Err(ColorError::InvalidCss),
+        },
+    };
+
+    let color = match space {
+        "srgb" => CssColor::Srgb(channels.into()),
+        "srgb-linear" => CssColor::SrgbLinear(channels.into()),
+        "oklab" => CssColor::Oklab(channels.into()),
+        "xyz-d50" => CssColor::Xyz(xyz::from_d50(channels.into())),
+        _ => CssColor::Xyz(channels.into()),
+    };
+
+    Ok(Alpha::new(color, alpha))
+}
+
+/// parses a css number or percentage, with `none` as 0
+fn number(word: &str, percent_of: f32) -> Result<f32, ColorError> {
+    let value = match word {
+        "none" => Ok(0.0),
+        _ => match word.strip_suffix('%') {
+            Some(percent) => percent.parse::<f32>().map(|p| p / 100.0 * percent_of),
+            None => word.parse::<f32>(),
+        },
+    };
+
+    value
+        .ok()
+        .filter(|v| v.is_finite())
+        .ok_or(ColorError::InvalidCss)
+}
+
+/// formats the channels separated by spaces, rounded to 4 decimals without trailing zeros
+fn channels(values: [f32; 3]) -> String {
+    values.map(number_to_css).join(" ")
+}
+
+fn number_to_css(value: f32) -> String {
+    let text = format!("{value:.4}");
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+
+    match text {
+        "-0" => "0".to_owned(),
+        text => text.to_owned(),
+    }
+}
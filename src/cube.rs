@@ -0,0 +1,113 @@
+use std::fmt;
+
+use crate::Lut3D;
+
+/// the largest grid the .cube format allows
+const MAX_SIZE: usize = 256;
+
+/// the ways a .cube lut can fail to parse
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CubeError {
+    /// there was no `LUT_3D_SIZE` line, or it came after the data
+    MissingSize,
+    /// the file is a 1d lut, which [Lut3D] can't hold
+    OneDimensional,
+    /// a line wasn't a keyword the 3d format allows, a comment, or three numbers. lines count from 1
+    InvalidLine { line: usize },
+    /// the number of entries didn't match `LUT_3D_SIZE`³
+    WrongLength { expected: usize, found: usize },
+}
+
+impl fmt::Display for CubeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CubeError::MissingSize => write!(f, "missing LUT_3D_SIZE"),
+            CubeError::OneDimensional => write!(f, "1d luts aren't supported"),
+            CubeError::InvalidLine { line } => write!(f, "invalid cube lut line {line}"),
+            CubeError::WrongLength { expected, found } => {
+                write!(f, "expected {expected} lut entries, found {found}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CubeError {}
+
+/// parses a .cube 3d lut, the format resolve and most grading tools exchange luts in. `TITLE`, comments and the keywords of other tools are skipped, and `DOMAIN_MIN` and `DOMAIN_MAX` set the input range the lut covers. the entries are in the order [Lut3D::new] samples them, red changing fastest
+///
+/// ```
+/// use tinycolors::{parse_cube, srgb};
+///
+/// let lut = parse_cube("LUT_3D_SIZE 2\n0 0 0\n1 0 0\n0 1 0\n1 1 0\n0 0 1\n1 0 1\n0 1 1\n1 1 1\n").unwrap();
+/// let color: srgb = lut.apply(srgb::RED);
+/// assert_eq!(color, srgb::RED);
+/// ```
+pub fn parse_cube(text: &str) -> Result<Lut3D, CubeError> {
+    let mut size = None;
+    let (mut min, mut max) = ([0.0; 3], [1.0; 3]);
+    let mut domain_line = 0;
+    let mut table = Vec::new();
+
+    for (i, line) in text.lines().enumerate() {
+        let invalid = CubeError::InvalidLine { line: i + 1 };
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        let Some(&keyword) = tokens.first() else {
+            continue;
+        };
+
+        if keyword.starts_with('#') {
+            continue;
+        }
+
+        if keyword.parse::<f32>().is_ok() {
+            if size.is_none() {
+                return Err(CubeError::MissingSize);
+            }
+            table.push(triple(&tokens).ok_or(invalid)?);
+            continue;
+        }
+
+        match keyword {
+            "LUT_3D_SIZE" => {
+                let n = match tokens[..] {
+                    [_, n] => n.parse::<usize>().ok(),
+                    _ => None,
+                };
+                size = Some(n.filter(|n| (2..=MAX_SIZE).contains(n)).ok_or(invalid)?);
+            }
+            "LUT_1D_SIZE" => return Err(CubeError::OneDimensional),
+            "DOMAIN_MIN" => {
+                min = triple(&tokens[1..]).ok_or(invalid)?;
+                domain_line = i + 1;
+            }
+            "DOMAIN_MAX" => {
+                max = triple(&tokens[1..]).ok_or(invalid)?;
+                domain_line = i + 1;
+            }
+            // TITLE and the keywords other tools add, like resolve's LUT_3D_INPUT_RANGE
+            _ if keyword.starts_with(|c: char| c.is_ascii_uppercase()) => {}
+            _ => return Err(invalid),
+        }
+    }
+
+    let size = size.ok_or(CubeError::MissingSize)?;
+    if table.len() != size * size * size {
+        return Err(CubeError::WrongLength {
+            expected: size * size * size,
+            found: table.len(),
+        });
+    }
+    if (0..3).any(|k| min[k] >= max[k]) {
+        return Err(CubeError::InvalidLine { line: domain_line });
+    }
+
+    Ok(Lut3D::from_table(size, table, (min, max)))
+}
+
+/// exactly three numbers
+fn triple(tokens: &[&str]) -> Option<[f32; 3]> {
+    match tokens {
+        [r, g, b] => Some([r.parse().ok()?, g.parse().ok()?, b.parse().ok()?]),
+        _ => None,
+    }
+}
@@ -0,0 +1,17 @@
+use crate::{oklab, srgb};
+
+impl srgb {
+    /// a typical just noticeable difference in [deltaEOK](srgb::delta_e_ok), a good default threshold for [srgb::visually_equal]
+    pub const JUST_NOTICEABLE: f32 = 0.02;
+
+    /// the perceptual difference between the colors, as euclidean distance in oklab. 0.0 for identical colors and about 1.0 between black and white
+    pub fn delta_e_ok(&self, other: &srgb) -> f32 {
+        let (a, b) = (oklab::from(*self), oklab::from(*other));
+        ((a.l - b.l).powi(2) + (a.a - b.a).powi(2) + (a.b - b.b).powi(2)).sqrt()
+    }
+
+    /// returns true if the colors are closer than `just_noticeable` in [deltaEOK](srgb::delta_e_ok). unlike comparing channels with a tolerance, this follows how different the colors actually look. [srgb::JUST_NOTICEABLE] is a good default
+    pub fn visually_equal(&self, other: &srgb, just_noticeable: f32) -> bool {
+        self.delta_e_ok(other) < just_noticeable
+    }
+}
@@ -0,0 +1,138 @@
+use crate::{Color, hue, oklab, oklch, srgb};
+
+/// a chain of adjustments to a color, started with [Color::edit]
+///
+/// the adjustments are made in oklch and only mapped back into srgb once, by [Edit::finish], so chaining them doesn't pile up conversion error or clip in between steps
+///
+/// ```
+/// use tinycolors::{Color, srgb};
+///
+/// let accent = srgb { r: 0.2, g: 0.4, b: 0.8 }
+///     .edit()
+///     .lighten(0.1)
+///     .saturate(0.2)
+///     .rotate_hue(30.0 / 360.0)
+///     .finish();
+/// ```
+#[must_use]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Edit {
+    color: oklch,
+}
+
+impl Edit {
+    /// starts an edit from any color, same as [Color::edit]
+    pub fn new(color: impl Color) -> Self {
+        let color: oklab = color.into();
+
+        Self {
+            color: color.into(),
+        }
+    }
+
+    /// adds `amount` to the oklab lightness
+    pub fn lighten(mut self, amount: f32) -> Self {
+        self.color.l += amount;
+        self
+    }
+
+    /// subtracts `amount` from the oklab lightness
+    pub fn darken(self, amount: f32) -> Self {
+        self.lighten(-amount)
+    }
+
+    /// multiplies the oklab lightness by `factor`, clamped to [0, 1], so dark colors move less than light ones. 0.0 gives black and 1.0 changes nothing
+    pub fn scale_lightness(mut self, factor: f32) -> Self {
+        self.color.l = (self.color.l * factor).clamp(0.0, 1.0);
+        self
+    }
+
+    /// scales the chroma up by `amount`, so 0.2 makes the color 20% more colorful
+    pub fn saturate(mut self, amount: f32) -> Self {
+        self.color.c = (self.color.c * (1.0 + amount)).max(0.0);
+        self
+    }
+
+    /// scales the chroma down by `amount`, so 1.0 leaves a gray of the same lightness
+    pub fn desaturate(self, amount: f32) -> Self {
+        self.saturate(-amount)
+    }
+
+    /// rotates the hue by `turns`. like every hue in this crate, a full turn is 1.0, so 30° is `30.0 / 360.0`
+    pub fn rotate_hue(mut self, turns: f32) -> Self {
+        self.color.h = hue::wrap(self.color.h + turns);
+        self
+    }
+
+    /// returns the adjusted color without mapping it into gamut
+    pub fn unmapped(self) -> oklch {
+        self.color
+    }
+
+    /// applies the adjustments, [gamut mapping](oklch::gamut_map) the result into srgb
+    pub fn finish(self) -> srgb {
+        self.color.gamut_map()
+    }
+}
+
+impl srgb {
+    /// shorthand for `self.edit().lighten(amount).finish()`, see [Edit::lighten]
+    pub fn lighten(self, amount: f32) -> srgb {
+        self.edit().lighten(amount).finish()
+    }
+
+    /// shorthand for `self.edit().darken(amount).finish()`, see [Edit::darken]
+    pub fn darken(self, amount: f32) -> srgb {
+        self.edit().darken(amount).finish()
+    }
+
+    /// shorthand for `self.edit().scale_lightness(factor).finish()`, see [Edit::scale_lightness]
+    pub fn scale_lightness(self, factor: f32) -> srgb {
+        self.edit().scale_lightness(factor).finish()
+    }
+
+    /// shorthand for `self.edit().saturate(amount).finish()`, see [Edit::saturate]
+    pub fn saturate(self, amount: f32) -> srgb {
+        self.edit().saturate(amount).finish()
+    }
+
+    /// shorthand for `self.edit().desaturate(amount).finish()`, see [Edit::desaturate]
+    pub fn desaturate(self, amount: f32) -> srgb {
+        self.edit().desaturate(amount).finish()
+    }
+
+    /// shorthand for `self.edit().rotate_hue(turns).finish()`, see [Edit::rotate_hue]
+    pub fn rotate_hue(self, turns: f32) -> srgb {
+        self.edit().rotate_hue(turns).finish()
+    }
+
+    /// [srgb::lighten] in place
+    pub fn lighten_mut(&mut self, amount: f32) {
+        *self = self.lighten(amount);
+    }
+
+    /// [srgb::darken] in place
+    pub fn darken_mut(&mut self, amount: f32) {
+        *self = self.darken(amount);
+    }
+
+    /// [srgb::scale_lightness] in place
+    pub fn scale_lightness_mut(&mut self, factor: f32) {
+        *self = self.scale_lightness(factor);
+    }
+
+    /// [srgb::saturate] in place
+    pub fn saturate_mut(&mut self, amount: f32) {
+        *self = self.saturate(amount);
+    }
+
+    /// [srgb::desaturate] in place
+    pub fn desaturate_mut(&mut self, amount: f32) {
+        *self = self.desaturate(amount);
+    }
+
+    /// [srgb::rotate_hue] in place
+    pub fn rotate_hue_mut(&mut self, turns: f32) {
+        *self = self.rotate_hue(turns);
+    }
+}
@@ -0,0 +1,32 @@
+use std::fmt;
+
+/// the ways a color can fail validation
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorError {
+    /// a channel was NaN or infinite, either in the input or after converting
+    NonFinite,
+    /// a string wasn't a valid `#rgb` or `#rrggbb` hex color
+    InvalidHex,
+    /// a slice didn't have one value per channel
+    WrongLength { expected: usize, found: usize },
+    /// a string wasn't valid css color syntax
+    InvalidCss,
+    /// the css named a color space this crate has no type for
+    UnsupportedSpace,
+}
+
+impl fmt::Display for ColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColorError::NonFinite => write!(f, "color has a NaN or infinite channel"),
+            ColorError::InvalidHex => write!(f, "invalid hex color"),
+            ColorError::WrongLength { expected, found } => {
+                write!(f, "expected {expected} channels, found {found}")
+            }
+            ColorError::InvalidCss => write!(f, "invalid css color"),
+            ColorError::UnsupportedSpace => write!(f, "unsupported color space"),
+        }
+    }
+}
+
+impl std::error::Error for ColorError {}
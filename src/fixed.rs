@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+use crate::srgb;
+
+/// an srgb color with each channel stored as q8.8 fixed point, a signed 16-bit integer counting 256ths. converting from [srgb] only scales by a power of two and rounds, which floats do exactly, so every platform lands on the same bits. that makes it safe to compare exactly or send over the network
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct QColor {
+    pub r: i16,
+    pub g: i16,
+    pub b: i16,
+}
+
+impl QColor {
+    /// how many steps make up 1.0
+    pub const ONE: i16 = 1 << 8;
+
+    /// the channels as little endian bytes, r first
+    pub fn to_le_bytes(&self) -> [u8; 6] {
+        let [r0, r1] = self.r.to_le_bytes();
+        let [g0, g1] = self.g.to_le_bytes();
+        let [b0, b1] = self.b.to_le_bytes();
+        [r0, r1, g0, g1, b0, b1]
+    }
+
+    /// the inverse of [QColor::to_le_bytes]
+    pub fn from_le_bytes([r0, r1, g0, g1, b0, b1]: [u8; 6]) -> Self {
+        Self {
+            r: i16::from_le_bytes([r0, r1]),
+            g: i16::from_le_bytes([g0, g1]),
+            b: i16::from_le_bytes([b0, b1]),
+        }
+    }
+
+    fn quantize(c: f32) -> i16 {
+        // the float to int cast saturates at the i16 range and maps NaN to 0
+        (c * Self::ONE as f32).round() as i16
+    }
+}
+
+/// rounds each channel to the nearest 256th, halfway cases away from zero. channels outside of about ±128 saturate
+impl From<srgb> for QColor {
+    fn from(value: srgb) -> Self {
+        Self {
+            r: QColor::quantize(value.r),
+            g: QColor::quantize(value.g),
+            b: QColor::quantize(value.b),
+        }
+    }
+}
+
+/// exact, every q8.8 value is representable as an f32
+impl From<QColor> for srgb {
+    fn from(value: QColor) -> Self {
+        let one = QColor::ONE as f32;
+
+        srgb {
+            r: value.r as f32 / one,
+            g: value.g as f32 / one,
+            b: value.b as f32 / one,
+        }
+    }
+}
@@ -0,0 +1,317 @@
+use crate::{oklab, oklch, rgb, srgb};
+
+/// how far outside of [0, 1] a linear channel may land and still count as in gamut, to absorb rounding in the conversions
+const GAMUT_EPSILON: f32 = 1e-5;
+
+/// the number of bisection steps used to find the gamut boundary, enough to resolve chroma well below what's visible
+const SEARCH_STEPS: usize = 24;
+
+/// how many lightnesses [oklch::clamp_preserve_hue] checks before refining around the closest one
+const BOUNDARY_SAMPLES: usize = 32;
+
+/// how far in front of a hull face a point has to be before it counts as outside
+const HULL_EPSILON: f64 = 1e-12;
+
+/// the strategies [oklch::gamut_map_with] can use to bring a color into the srgb gamut. they're loosely modeled on icc rendering intents, but work on one color at a time
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Intent {
+    /// reduce oklch chroma until the color fits, keeping lightness and hue exactly
+    #[default]
+    ChromaReduction,
+    /// clamp each srgb channel to [0, 1]. the cheapest option, but it shifts hue and lightness
+    Clip,
+    /// scale the color in linear rgb towards the gray of the same lightness until it fits. the channels keep their proportions relative to the gray, so the result stays smooth across neighboring colors
+    PerceptualScale,
+}
+
+impl oklch {
+    /// the largest chroma an oklch color with this lightness and hue can have while staying inside the srgb gamut. 0.0 for lightness outside of (0, 1)
+    pub fn max_chroma(lightness: f32, hue: f32) -> f32 {
+        if lightness <= 0.0 || lightness >= 1.0 {
+            return 0.0;
+        }
+
+        // no srgb color has a chroma above about 0.33
+        boundary_chroma(lightness, hue, 0.5)
+    }
+
+    /// maps the color into the srgb gamut by reducing chroma until it fits, keeping lightness and hue. colors that are already in gamut are converted unchanged
+    pub fn gamut_map(self) -> srgb {
+        self.gamut_map_with(Intent::ChromaReduction)
+    }
+
+    /// maps the color to the closest in gamut color with the same hue, moving lightness as well as chroma if that's closer. unlike [oklch::gamut_map], which keeps lightness and can leave very light or dark colors washed out, this finds the nearest point of the gamut boundary within the hue's slice of oklch, by distance in oklab. colors that are already in gamut are converted unchanged
+    pub fn clamp_preserve_hue(self) -> srgb {
+        if in_gamut(self) {
+            return clamp(self.into());
+        }
+
+        let h = self.h;
+        // the boundary point at lightness l that's closest to the color
+        let boundary = |l: f32| oklch {
+            l,
+            c: self.c.min(oklch::max_chroma(l, h)),
+            h,
+        };
+        let distance = |l: f32| {
+            let point = boundary(l);
+            (point.l - self.l).powi(2) + (point.c - self.c).powi(2)
+        };
+
+        let step = 1.0 / BOUNDARY_SAMPLES as f32;
+        let closest = (0..=BOUNDARY_SAMPLES)
+            .map(|i| i as f32 * step)
+            .min_by(|&a, &b| distance(a).total_cmp(&distance(b)))
+            .unwrap_or(self.l);
+
+        let (mut low, mut high) = ((closest - step).max(0.0), (closest + step).min(1.0));
+        for _ in 0..SEARCH_STEPS {
+            let (a, b) = (low + (high - low) / 3.0, high - (high - low) / 3.0);
+            if distance(a) < distance(b) {
+                high = b;
+            } else {
+                low = a;
+            }
+        }
+
+        clamp(boundary((low + high) / 2.0).into())
+    }
+
+    /// maps the color into the srgb gamut using the given [Intent]. colors that are already in gamut are converted unchanged by every intent
+    pub fn gamut_map_with(self, intent: Intent) -> srgb {
+        if intent != Intent::Clip {
+            if self.l >= 1.0 {
+                return srgb::WHITE;
+            }
+            if self.l <= 0.0 {
+                return srgb::BLACK;
+            }
+        }
+
+        if in_gamut(self) {
+            return clamp(self.into());
+        }
+
+        match intent {
+            Intent::ChromaReduction => clamp(
+                oklch {
+                    c: boundary_chroma(self.l, self.h, self.c),
+                    ..self
+                }
+                .into(),
+            ),
+            Intent::Clip => clamp(self.into()),
+            Intent::PerceptualScale => {
+                let color: [f32; 3] = rgb::from(self).into();
+                // an oklab gray with lightness l is l³ in every linear channel
+                let gray = self.l * self.l * self.l;
+
+                // the largest fraction of the way from the gray to the color that keeps every channel in range
+                let k = color.iter().fold(1.0f32, |k, &c| {
+                    if c > 1.0 {
+                        k.min((1.0 - gray) / (c - gray))
+                    } else if c < 0.0 {
+                        k.min(gray / (gray - c))
+                    } else {
+                        k
+                    }
+                });
+
+                clamp(color.map(|c| gray + (c - gray) * k).into())
+            }
+        }
+    }
+}
+
+impl srgb {
+    /// [oklch::clamp_preserve_hue] for a color whose channels may be out of range
+    pub fn clamp_preserve_hue(self) -> srgb {
+        oklch::from(self).clamp_preserve_hue()
+    }
+
+    /// the lightness tones that make up a material design 3 tonal palette
+    pub const TONES: [u8; 13] = [0, 10, 20, 30, 40, 50, 60, 70, 80, 90, 95, 99, 100];
+
+    /// generates a material design 3 style tonal palette, one color for each of [srgb::TONES]. every tone keeps the oklch hue and chroma of this color with the lightness set to the tone, then gets [gamut mapped](oklch::gamut_map)
+    pub fn tonal_palette(&self) -> [srgb; 13] {
+        let seed = oklch::from(*self);
+
+        Self::TONES.map(|tone| {
+            oklch {
+                l: tone as f32 / 100.0,
+                ..seed
+            }
+            .gamut_map()
+        })
+    }
+}
+
+/// yields `count` colors evenly spaced around the oklch hue wheel, starting from hue 0, all with the given lightness and chroma. each one is [gamut mapped](oklch::gamut_map), so hues that can't reach the chroma come out less saturated
+pub fn hue_wheel(count: usize, lightness: f32, chroma: f32) -> impl Iterator<Item = srgb> {
+    (0..count).map(move |i| {
+        oklch {
+            l: lightness,
+            c: chroma,
+            h: i as f32 / count as f32,
+        }
+        .gamut_map()
+    })
+}
+
+/// the volume of the convex hull of the colors in oklab, for comparing how much of the color space palettes span. the eight corners of the srgb cube span about 0.058. fewer than four colors, or colors that all lie on one plane, have no volume and give 0
+pub fn gamut_volume(colors: &[srgb]) -> f32 {
+    let points: Vec<[f64; 3]> = colors
+        .iter()
+        .map(|&color| oklab::from(color).into())
+        .map(|lab: [f32; 3]| lab.map(f64::from))
+        .collect();
+
+    hull_volume(&points) as f32
+}
+
+/// bisects for the largest in gamut chroma below `upper`
+fn boundary_chroma(l: f32, h: f32, upper: f32) -> f32 {
+    let (mut low, mut high) = (0.0, upper);
+    for _ in 0..SEARCH_STEPS {
+        let mid = (low + high) / 2.0;
+        if in_gamut(oklch { l, c: mid, h }) {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+
+    low
+}
+
+fn in_gamut(color: oklch) -> bool {
+    let rgb { r, g, b } = color.into();
+
+    [r, g, b]
+        .iter()
+        .all(|c| (-GAMUT_EPSILON..=1.0 + GAMUT_EPSILON).contains(c))
+}
+
+fn clamp(rgb { r, g, b }: rgb) -> srgb {
+    rgb {
+        r: r.clamp(0.0, 1.0),
+        g: g.clamp(0.0, 1.0),
+        b: b.clamp(0.0, 1.0),
+    }
+    .into()
+}
+
+/// the volume of the convex hull of the points, built incrementally: each point outside the hull so far replaces the faces it can see with a fan of faces to their horizon
+fn hull_volume(points: &[[f64; 3]]) -> f64 {
+    let Some(seed) = hull_seed(points) else {
+        return 0.0;
+    };
+    let inside = seed
+        .map(|i| points[i])
+        .into_iter()
+        .fold([0.0; 3], |sum, p| [0, 1, 2].map(|k| sum[k] + p[k] / 4.0));
+
+    let [a, b, c, d] = seed;
+    let mut faces: Vec<[usize; 3]> = [[a, b, c], [a, b, d], [a, c, d], [b, c, d]]
+        .into_iter()
+        .map(|[a, b, c]| {
+            // faces are wound so the inside is behind them
+            if height(points, [a, b, c], inside) > 0.0 {
+                [a, c, b]
+            } else {
+                [a, b, c]
+            }
+        })
+        .collect();
+
+    for (i, &point) in points.iter().enumerate() {
+        if seed.contains(&i) {
+            continue;
+        }
+
+        let visible: Vec<bool> = faces
+            .iter()
+            .map(|&face| height(points, face, point) > HULL_EPSILON)
+            .collect();
+        if !visible.contains(&true) {
+            continue;
+        }
+
+        // an edge of a visible face is on the horizon if the face across it stays
+        let mut horizon = Vec::new();
+        for (face, _) in faces.iter().zip(&visible).filter(|(_, visible)| **visible) {
+            for (u, v) in [(face[0], face[1]), (face[1], face[2]), (face[2], face[0])] {
+                let shared = faces.iter().zip(&visible).any(|(other, visible)| {
+                    *visible
+                        && [(0, 1), (1, 2), (2, 0)]
+                            .iter()
+                            .any(|&(x, y)| other[x] == v && other[y] == u)
+                });
+                if !shared {
+                    horizon.push((u, v));
+                }
+            }
+        }
+
+        faces = faces
+            .into_iter()
+            .zip(visible)
+            .filter(|(_, visible)| !visible)
+            .map(|(face, _)| face)
+            .chain(horizon.into_iter().map(|(u, v)| [u, v, i]))
+            .collect();
+    }
+
+    faces
+        .into_iter()
+        .map(|face| -height(points, face, inside) / 6.0)
+        .sum()
+}
+
+/// four points of the set spanning a tetrahedron, or None if they're all on one plane
+fn hull_seed(points: &[[f64; 3]]) -> Option<[usize; 4]> {
+    if points.is_empty() {
+        return None;
+    }
+
+    let farthest = |score: &dyn Fn([f64; 3]) -> f64| {
+        (0..points.len())
+            .map(|i| (i, score(points[i]).abs()))
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .filter(|&(_, score)| score > HULL_EPSILON)
+            .map(|(i, _)| i)
+    };
+
+    let a = 0;
+    let b = farthest(&|p| length(sub(p, points[a])))?;
+    let c = farthest(&|p| length(cross(sub(points[b], points[a]), sub(p, points[a]))))?;
+    let d = farthest(&|p| height(points, [a, b, c], p))?;
+
+    Some([a, b, c, d])
+}
+
+/// how far in front of the face `p` is, scaled by twice the face's area
+fn height(points: &[[f64; 3]], [a, b, c]: [usize; 3], p: [f64; 3]) -> f64 {
+    let (a, b, c) = (points[a], points[b], points[c]);
+    dot(cross(sub(b, a), sub(c, a)), sub(p, a))
+}
+
+fn sub(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn length(a: [f64; 3]) -> f64 {
+    dot(a, a).sqrt()
+}
@@ -0,0 +1,75 @@
+use std::fmt;
+
+use crate::{bytes, srgb};
+
+/// the ways a gimp palette can fail to parse
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GplError {
+    /// the text didn't start with the `GIMP Palette` header
+    MissingHeader,
+    /// a line wasn't a comment, a `Name:` or `Columns:` line, or an `R G B Name` entry with channels from 0 to 255. lines count from 1
+    InvalidLine { line: usize },
+}
+
+impl fmt::Display for GplError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GplError::MissingHeader => write!(f, "missing GIMP Palette header"),
+            GplError::InvalidLine { line } => write!(f, "invalid palette entry on line {line}"),
+        }
+    }
+}
+
+impl std::error::Error for GplError {}
+
+/// parses a gimp palette (.gpl), the plain text format gimp, krita, inkscape and paint.net use, into its colors and their names. comments, blank lines and the `Name:` and `Columns:` lines are skipped, and colors without a name get an empty one
+///
+/// ```
+/// use tinycolors::{parse_gpl, srgb};
+///
+/// let palette = parse_gpl("GIMP Palette\nName: bw\n#\n  0   0   0\tblack\n255 255 255\twhite\n").unwrap();
+/// assert_eq!(palette[1], ("white".to_string(), srgb::WHITE));
+/// ```
+pub fn parse_gpl(text: &str) -> Result<Vec<(String, srgb)>, GplError> {
+    let mut lines = text
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty());
+
+    match lines.next() {
+        Some((_, header)) if header.trim() == "GIMP Palette" => {}
+        _ => return Err(GplError::MissingHeader),
+    }
+
+    let mut colors = Vec::new();
+    for (i, line) in lines {
+        let line = line.trim();
+        if line.starts_with('#') || line.starts_with("Name:") || line.starts_with("Columns:") {
+            continue;
+        }
+
+        let mut parts = line.split_whitespace();
+        let mut channel = || parts.next().and_then(|c| c.parse::<u8>().ok());
+        let (Some(r), Some(g), Some(b)) = (channel(), channel(), channel()) else {
+            return Err(GplError::InvalidLine { line: i + 1 });
+        };
+
+        let name = parts.collect::<Vec<_>>().join(" ");
+        colors.push((name, [r, g, b].map(bytes::from_u8).into()));
+    }
+
+    Ok(colors)
+}
+
+/// writes the colors as a gimp palette (.gpl) called `name`, the inverse of [parse_gpl]. channels are clamped and quantized to bytes, and line breaks in names are replaced with spaces so every entry stays on one line. there's no `Columns:` line, so gimp picks the layout
+pub fn to_gpl(name: &str, colors: &[(String, srgb)]) -> String {
+    let one_line = |s: &str| s.replace(['\r', '\n'], " ");
+
+    let mut gpl = format!("GIMP Palette\nName: {}\n#\n", one_line(name));
+    for (name, color) in colors {
+        let [r, g, b] = [color.r, color.g, color.b].map(bytes::to_u8);
+        gpl += &format!("{r:3} {g:3} {b:3}\t{}\n", one_line(name));
+    }
+
+    gpl
+}
@@ -0,0 +1,265 @@
+use crate::{Color, oklab, srgb};
+
+/// how many points [Gradient::project] checks per segment before refining around the closest one
+const PROJECT_SAMPLES: usize = 16;
+
+/// the number of ternary search steps [Gradient::project] refines with
+const PROJECT_STEPS: usize = 32;
+
+/// a color gradient made of stops at positions between 0 and 1
+///
+/// colors are interpolated channel by channel in the space the gradient is stored in, so a `Gradient<oklab>` blends perceptually and a `Gradient<rgb>` blends like light. in the cylindrical spaces the hue channel is interpolated like any other and doesn't wrap around, so those are better avoided
+///
+/// ```
+/// use tinycolors::{Gradient, oklab, srgb};
+///
+/// let gradient = Gradient::new([(0.0, oklab::from(srgb::BLACK)), (1.0, oklab::from(srgb::WHITE))]);
+/// let gray = srgb::from(gradient.sample(0.5));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gradient<C> {
+    stops: Vec<(f32, C)>,
+    /// applied to `t` before looking up the stops
+    easing: Easing,
+}
+
+impl<C: Color> Gradient<C> {
+    /// creates a gradient from `(position, color)` stops, which don't need to be in order
+    ///
+    /// # Panics
+    ///
+    /// panics if there are no stops
+    pub fn new(stops: impl IntoIterator<Item = (f32, C)>) -> Self {
+        let mut stops: Vec<_> = stops.into_iter().collect();
+        assert!(!stops.is_empty(), "a gradient needs at least one stop");
+
+        stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Self {
+            stops,
+            easing: Easing::Linear,
+        }
+    }
+
+    /// creates a gradient with the colors spread evenly from 0 to 1
+    ///
+    /// # Panics
+    ///
+    /// panics if there are no colors
+    pub fn evenly_spaced(colors: impl IntoIterator<Item = C>) -> Self {
+        let colors: Vec<_> = colors.into_iter().collect();
+        let last = colors.len().saturating_sub(1).max(1) as f32;
+
+        Self::new(
+            colors
+                .into_iter()
+                .enumerate()
+                .map(|(i, color)| (i as f32 / last, color)),
+        )
+    }
+
+    /// eases `t` through `easing`, like [smoothstep], before every lookup, so the colors change on a curve instead of at a steady rate. the stops stay where they are, only the pace of the walk through them changes
+    pub fn with_easing(self, easing: fn(f32) -> f32) -> Gradient<C> {
+        Self {
+            easing: Easing::Custom(easing),
+            ..self
+        }
+    }
+
+    /// the stops, sorted by position
+    pub fn stops(&self) -> &[(f32, C)] {
+        &self.stops
+    }
+
+    /// samples the gradient at `t`, interpolating linearly between the surrounding stops. `t` outside of the stops gives the color of the nearest end, and a NaN `t` gives the first stop
+    pub fn sample(&self, t: f32) -> C {
+        self.sample_with(t, |segment, s| {
+            let (a, b) = (segment.values[1], segment.values[2]);
+            a + (b - a) * s
+        })
+    }
+
+    /// samples the gradient at `t` with monotone cubic interpolation, which removes the visible kinks that [Gradient::sample] has at every stop
+    ///
+    /// the curve is monotone between stops, so it never overshoots the stop colors. with only two stops it's the same as [Gradient::sample]
+    pub fn sample_smooth(&self, t: f32) -> C {
+        self.sample_with(t, |segment, s| {
+            let [x0, x1, x2, x3] = segment.positions;
+            let [y0, y1, y2, y3] = segment.values;
+
+            let h = x2 - x1;
+            let d = slope(x1, y1, x2, y2);
+            // the end stops only have the one slope to go on
+            let mut m1 = if segment.first {
+                d
+            } else {
+                tangent(slope(x0, y0, x1, y1), d)
+            };
+            let mut m2 = if segment.last {
+                d
+            } else {
+                tangent(d, slope(x2, y2, x3, y3))
+            };
+
+            // fritsch-carlson: limit the tangents so the segment can't overshoot
+            if d == 0.0 {
+                (m1, m2) = (0.0, 0.0);
+            } else {
+                let (a, b) = (m1 / d, m2 / d);
+                let r = a * a + b * b;
+                if r > 9.0 {
+                    let tau = 3.0 / r.sqrt();
+                    (m1, m2) = (tau * a * d, tau * b * d);
+                }
+            }
+
+            let (s2, s3) = (s * s, s * s * s);
+            (2.0 * s3 - 3.0 * s2 + 1.0) * y1
+                + (s3 - 2.0 * s2 + s) * h * m1
+                + (-2.0 * s3 + 3.0 * s2) * y2
+                + (s3 - s2) * h * m2
+        })
+    }
+
+    /// the position whose [sample](Gradient::sample) looks closest to `color`, by distance in oklab. handy for mapping a measured color back onto a legend
+    ///
+    /// each segment is scanned coarsely and then refined around its closest point. a gradient with a single stop gives that stop's position
+    pub fn project(&self, color: srgb) -> f32 {
+        let target = oklab::from(color);
+        let distance = |t: f32| {
+            let sample: oklab = self.sample(t).into();
+            (sample.l - target.l).powi(2)
+                + (sample.a - target.a).powi(2)
+                + (sample.b - target.b).powi(2)
+        };
+
+        let mut best = (self.stops[0].0, distance(self.stops[0].0));
+        for pair in self.stops.windows(2) {
+            let (start, end) = (pair[0].0, pair[1].0);
+            let step = (end - start) / PROJECT_SAMPLES as f32;
+            if step <= 0.0 {
+                continue;
+            }
+
+            let closest = (0..=PROJECT_SAMPLES)
+                .map(|i| start + step * i as f32)
+                .min_by(|&a, &b| distance(a).total_cmp(&distance(b)))
+                .unwrap_or(start);
+
+            let (mut low, mut high) = ((closest - step).max(start), (closest + step).min(end));
+            for _ in 0..PROJECT_STEPS {
+                let (a, b) = (low + (high - low) / 3.0, high - (high - low) / 3.0);
+                if distance(a) < distance(b) {
+                    high = b;
+                } else {
+                    low = a;
+                }
+            }
+
+            let t = (low + high) / 2.0;
+            let d = distance(t);
+            if d < best.1 {
+                best = (t, d);
+            }
+        }
+
+        best.0
+    }
+
+    /// finds the segment `t` falls in and interpolates each channel with `f`, given the segment and the position within it
+    fn sample_with(&self, t: f32, f: impl Fn(&Segment, f32) -> f32) -> C {
+        let t = self.easing.apply(t);
+        let stops = &self.stops;
+        let last = stops.len() - 1;
+
+        // NaN fails every comparison, so it would otherwise fall through to a segment that doesn't exist
+        if t.is_nan() || t <= stops[0].0 {
+            return stops[0].1;
+        }
+        if t >= stops[last].0 {
+            return stops[last].1;
+        }
+
+        let i = stops.partition_point(|(position, _)| *position <= t) - 1;
+        let (x1, x2) = (stops[i].0, stops[i + 1].0);
+        let s = if x2 > x1 { (t - x1) / (x2 - x1) } else { 1.0 };
+
+        // the neighbouring stops, repeated at the ends
+        let indices = [i.saturating_sub(1), i, i + 1, (i + 2).min(last)];
+        let channels: [[f32; 3]; 4] = indices.map(|i| stops[i].1.into());
+
+        let mut result = [0.0; 3];
+        for (channel, result) in result.iter_mut().enumerate() {
+            let segment = Segment {
+                positions: indices.map(|i| stops[i].0),
+                values: channels.map(|c| c[channel]),
+                first: i == 0,
+                last: i + 1 == last,
+            };
+            *result = f(&segment, s);
+        }
+        result.into()
+    }
+}
+
+/// a diverging scale for data around a meaningful center, like blue-white-red for correlations. `low` sits at 0, `mid` at 0.5 and `high` at 1, blended in oklab
+pub fn diverging(low: srgb, mid: srgb, high: srgb) -> Gradient<oklab> {
+    Gradient::new([(0.0, low.into()), (0.5, mid.into()), (1.0, high.into())])
+}
+
+/// eases in and out with 3t² - 2t³, which starts and ends with zero slope. `t` is clamped to [0, 1]
+pub fn smoothstep(t: f32) -> f32 {
+    let t = t.clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
+
+/// the pace [Gradient::with_easing] walks through a gradient at
+#[derive(Clone, Copy, Debug, Default)]
+enum Easing {
+    /// a steady pace, `t` unchanged
+    #[default]
+    Linear,
+    /// any function that maps [0, 1] onto [0, 1]
+    Custom(fn(f32) -> f32),
+}
+
+impl Easing {
+    fn apply(self, t: f32) -> f32 {
+        match self {
+            Easing::Linear => t,
+            Easing::Custom(f) => f(t),
+        }
+    }
+}
+
+/// custom easings are equal if they point at the same function. a copy keeps its address, so this is reflexive, but the same function can end up at different addresses in different codegen units, so two gradients eased by it may still compare unequal
+impl PartialEq for Easing {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Easing::Linear, Easing::Linear) => true,
+            (Easing::Custom(a), Easing::Custom(b)) => std::ptr::fn_addr_eq(*a, *b),
+            _ => false,
+        }
+    }
+}
+
+/// one channel of the stops around a segment, the segment itself being between index 1 and 2
+struct Segment {
+    positions: [f32; 4],
+    values: [f32; 4],
+    first: bool,
+    last: bool,
+}
+
+fn slope(x0: f32, y0: f32, x1: f32, y1: f32) -> f32 {
+    if x1 > x0 { (y1 - y0) / (x1 - x0) } else { 0.0 }
+}
+
+/// the tangent at a stop, from the slopes on either side of it
+fn tangent(before: f32, after: f32) -> f32 {
+    if before * after <= 0.0 {
+        // a local extremum stays flat
+        0.0
+    } else {
+        (before + after) / 2.0
+    }
+}
@@ -0,0 +1,45 @@
+use std::hash::{Hash, Hasher};
+
+use crate::Color;
+
+/// a wrapper that lets a color be used as a HashMap or HashSet key, by hashing and comparing the bit patterns of its channels
+///
+/// before comparing, `-0.0` is treated as `0.0` and every NaN is treated as the same NaN, so unlike the float comparison NaN channels are equal to each other. otherwise two colors are only equal if their channels are exactly the same
+#[derive(Clone, Copy, Debug, Default)]
+pub struct HashableColor<C>(pub C);
+
+impl<C: Color> HashableColor<C> {
+    fn bits(&self) -> [u32; 3] {
+        let channels: [f32; 3] = self.0.into();
+
+        channels.map(|c| {
+            if c.is_nan() {
+                f32::NAN.to_bits()
+            } else if c == 0.0 {
+                0.0f32.to_bits()
+            } else {
+                c.to_bits()
+            }
+        })
+    }
+}
+
+impl<C: Color> From<C> for HashableColor<C> {
+    fn from(value: C) -> Self {
+        Self(value)
+    }
+}
+
+impl<C: Color> PartialEq for HashableColor<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bits() == other.bits()
+    }
+}
+
+impl<C: Color> Eq for HashableColor<C> {}
+
+impl<C: Color> Hash for HashableColor<C> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bits().hash(state);
+    }
+}
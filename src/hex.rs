@@ -0,0 +1,80 @@
+use crate::{ColorError, bytes, srgb};
+
+/// parses a hex color literal into an [srgb] at compile time, accepting the same `#rgb` and `#rrggbb` forms as [srgb::from_hex]. a malformed literal is a compile error, so it's handy for palette constants
+///
+/// ```
+/// use tinycolors::{color, srgb};
+///
+/// const ACCENT: srgb = color!("#3366cc");
+/// assert_eq!(Ok(ACCENT), srgb::from_hex("#3366cc"));
+/// ```
+///
+/// ```compile_fail
+/// const TYPO: tinycolors::srgb = tinycolors::color!("#33g6cc");
+/// ```
+#[macro_export]
+macro_rules! color {
+    ($hex:literal) => {
+        const {
+            match $crate::srgb::from_hex_const($hex) {
+                Some(color) => color,
+                None => panic!("invalid hex color literal"),
+            }
+        }
+    };
+}
+
+impl srgb {
+    /// parses a css style hex color, `#rgb` or `#rrggbb`. the leading `#` is optional and either case works
+    pub fn from_hex(hex: &str) -> Result<srgb, ColorError> {
+        srgb::from_hex_const(hex).ok_or(ColorError::InvalidHex)
+    }
+
+    /// [srgb::from_hex] for const contexts, returning None for malformed input. [color!] wraps it for literals
+    pub const fn from_hex_const(hex: &str) -> Option<srgb> {
+        let digits = match hex.as_bytes() {
+            [b'#', rest @ ..] => rest,
+            digits => digits,
+        };
+
+        let bytes = match *digits {
+            [r, g, b] => match (digit(r), digit(g), digit(b)) {
+                (Some(r), Some(g), Some(b)) => [r * 0x11, g * 0x11, b * 0x11],
+                _ => return None,
+            },
+            [r1, r0, g1, g0, b1, b0] => match (pair(r1, r0), pair(g1, g0), pair(b1, b0)) {
+                (Some(r), Some(g), Some(b)) => [r, g, b],
+                _ => return None,
+            },
+            _ => return None,
+        };
+
+        Some(srgb {
+            r: bytes::from_u8(bytes[0]),
+            g: bytes::from_u8(bytes[1]),
+            b: bytes::from_u8(bytes[2]),
+        })
+    }
+
+    /// formats the color as a lowercase `#rrggbb` string. channels are clamped to [0, 1] before quantizing
+    pub fn to_hex(&self) -> String {
+        let [r, g, b] = [self.r, self.g, self.b].map(bytes::to_u8);
+        format!("#{r:02x}{g:02x}{b:02x}")
+    }
+}
+
+const fn digit(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+const fn pair(high: u8, low: u8) -> Option<u8> {
+    match (digit(high), digit(low)) {
+        (Some(high), Some(low)) => Some(high * 16 + low),
+        _ => None,
+    }
+}
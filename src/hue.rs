@@ -0,0 +1,121 @@
+use std::f32::consts::TAU;
+
+use crate::{cielch, hsi, hsl, hsv, okhsl, okhsv, oklch, srgb};
+
+/// the oklch hue that scores warmest, between red and orange. the coolest hue sits opposite it, between cyan and blue
+const WARMEST_HUE: f32 = 45.0 / 360.0;
+
+/// the oklch chroma at which a color counts as fully saturated for [srgb::warmth]. grays are neither warm nor cool
+const WARMTH_FULL_CHROMA: f32 = 0.1;
+
+/// wraps a hue into [0, 1)
+pub(crate) fn wrap(h: f32) -> f32 {
+    let h = h.rem_euclid(1.0);
+    // rem_euclid rounds tiny negative hues up to exactly 1.0
+    if h >= 1.0 { 0.0 } else { h }
+}
+
+/// the shortest distance between two hues in turns, going whichever way around the wheel is closer, so it's always in [0, 0.5]. `hue_distance(0.95, 0.05)` is 0.1
+pub fn hue_distance(a: f32, b: f32) -> f32 {
+    let d = wrap(a - b);
+    d.min(1.0 - d)
+}
+
+/// interpolates between two hues the short way around the wheel, wrapped into [0, 1)
+pub(crate) fn lerp_hue(a: f32, b: f32, t: f32) -> f32 {
+    let mut d = wrap(b - a);
+    if d > 0.5 {
+        d -= 1.0;
+    }
+
+    wrap(a + d * t)
+}
+
+impl hsl {
+    /// wraps the hue into [0, 1), leaving the other channels alone
+    pub fn wrap_hue(self) -> Self {
+        Self {
+            h: wrap(self.h),
+            ..self
+        }
+    }
+}
+
+impl hsv {
+    /// wraps the hue into [0, 1), leaving the other channels alone
+    pub fn wrap_hue(self) -> Self {
+        Self {
+            h: wrap(self.h),
+            ..self
+        }
+    }
+}
+
+impl okhsl {
+    /// wraps the hue into [0, 1), leaving the other channels alone
+    pub fn wrap_hue(self) -> Self {
+        Self {
+            h: wrap(self.h),
+            ..self
+        }
+    }
+}
+
+impl okhsv {
+    /// wraps the hue into [0, 1), leaving the other channels alone
+    pub fn wrap_hue(self) -> Self {
+        Self {
+            h: wrap(self.h),
+            ..self
+        }
+    }
+}
+
+impl oklch {
+    /// wraps the hue into [0, 1), leaving the other channels alone
+    pub fn wrap_hue(self) -> Self {
+        Self {
+            h: wrap(self.h),
+            ..self
+        }
+    }
+}
+
+impl cielch {
+    /// wraps the hue into [0, 1), leaving the other channels alone
+    pub fn wrap_hue(self) -> Self {
+        Self {
+            h: wrap(self.h),
+            ..self
+        }
+    }
+}
+
+impl hsi {
+    /// wraps the hue into [0, 1), leaving the other channels alone
+    pub fn wrap_hue(self) -> Self {
+        Self {
+            h: wrap(self.h),
+            ..self
+        }
+    }
+}
+
+impl srgb {
+    /// how warm the color looks, from 1.0 for reds and oranges to -1.0 for cyans and blues. it follows the cosine of the oklch hue's distance from orange red, faded out towards 0.0 as the color gets closer to gray
+    pub fn warmth(&self) -> f32 {
+        let color = oklch::from(*self);
+        let saturation = (color.c / WARMTH_FULL_CHROMA).min(1.0);
+
+        ((color.h - WARMEST_HUE) * TAU).cos() * saturation
+    }
+
+    /// moves the color to the given oklch hue in turns, keeping its lightness and chroma so shading survives, then [gamut maps](oklch::gamut_map) it. grays have no chroma to carry the new hue, so nearly achromatic colors barely change
+    pub fn recolor(self, target_hue: f32) -> srgb {
+        oklch {
+            h: wrap(target_hue),
+            ..oklch::from(self)
+        }
+        .gamut_map()
+    }
+}
@@ -0,0 +1,56 @@
+use ::approx::{AbsDiffEq, RelativeEq};
+
+use crate::{
+    cielab, cielch, cmyk, hsi, hsl, hsv, okhsl, okhsv, oklab, oklch, rgb, srgb, srgba, xyy, xyz,
+    ycbcr,
+};
+
+/// compares channel by channel, like comparing the arrays the colors convert to. hue is compared like any other channel, so hues just either side of 0 aren't equal
+macro_rules! impl_approx {
+    ($($color:ident: $channels:literal),* $(,)?) => {$(
+        impl AbsDiffEq for $color {
+            type Epsilon = f32;
+
+            fn default_epsilon() -> f32 {
+                f32::default_epsilon()
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: f32) -> bool {
+                let (a, b): ([f32; $channels], [f32; $channels]) = ((*self).into(), (*other).into());
+                a.iter().zip(&b).all(|(a, b)| a.abs_diff_eq(b, epsilon))
+            }
+        }
+
+        impl RelativeEq for $color {
+            fn default_max_relative() -> f32 {
+                f32::default_max_relative()
+            }
+
+            fn relative_eq(&self, other: &Self, epsilon: f32, max_relative: f32) -> bool {
+                let (a, b): ([f32; $channels], [f32; $channels]) = ((*self).into(), (*other).into());
+                a.iter()
+                    .zip(&b)
+                    .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+            }
+        }
+    )*};
+}
+
+impl_approx!(
+    srgb: 3,
+    rgb: 3,
+    oklab: 3,
+    okhsl: 3,
+    okhsv: 3,
+    hsl: 3,
+    hsv: 3,
+    xyz: 3,
+    xyy: 3,
+    cielab: 3,
+    cielch: 3,
+    hsi: 3,
+    ycbcr: 3,
+    oklch: 3,
+    srgba: 4,
+    cmyk: 4,
+);
@@ -0,0 +1,15 @@
+use ::csscolorparser::Color;
+
+use crate::srgba;
+
+impl From<Color> for srgba {
+    fn from(Color { r, g, b, a }: Color) -> Self {
+        Self { r, g, b, a }
+    }
+}
+
+impl From<srgba> for Color {
+    fn from(srgba { r, g, b, a }: srgba) -> Self {
+        Color::new(r, g, b, a)
+    }
+}
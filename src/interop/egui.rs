@@ -0,0 +1,39 @@
+use ::ecolor::Color32;
+
+use crate::{bytes, srgb, srgba};
+
+// Color32 is srgb encoded, like srgb and srgba, so only quantization and premultiplication need handling. both are left to ecolor so they match egui exactly
+
+impl From<srgb> for Color32 {
+    fn from(value: srgb) -> Self {
+        Color32::from_rgb(
+            bytes::to_u8(value.r),
+            bytes::to_u8(value.g),
+            bytes::to_u8(value.b),
+        )
+    }
+}
+
+/// the alpha is dropped, see the srgba conversion to keep it
+impl From<Color32> for srgb {
+    fn from(value: Color32) -> Self {
+        srgba::from(value).rgb()
+    }
+}
+
+impl From<srgba> for Color32 {
+    fn from(value: srgba) -> Self {
+        Color32::from_rgba_unmultiplied(
+            bytes::to_u8(value.r),
+            bytes::to_u8(value.g),
+            bytes::to_u8(value.b),
+            bytes::to_u8(value.a),
+        )
+    }
+}
+
+impl From<Color32> for srgba {
+    fn from(value: Color32) -> Self {
+        value.to_srgba_unmultiplied().map(bytes::from_u8).into()
+    }
+}
@@ -0,0 +1,17 @@
+use ::half::f16;
+
+use crate::rgb;
+
+/// the channels are taken as linear, the way they're stored in float textures like rgba16f
+impl From<[f16; 3]> for rgb {
+    fn from(value: [f16; 3]) -> Self {
+        value.map(f16::to_f32).into()
+    }
+}
+
+/// values outside of what f16 can represent saturate to infinity
+impl From<rgb> for [f16; 3] {
+    fn from(value: rgb) -> Self {
+        <[f32; 3]>::from(value).map(f16::from_f32)
+    }
+}
@@ -0,0 +1,29 @@
+use ::image::{Rgb, Rgba};
+
+use crate::{bytes, srgb, srgba};
+
+impl From<Rgb<u8>> for srgb {
+    fn from(Rgb([r, g, b]): Rgb<u8>) -> Self {
+        [r, g, b].map(bytes::from_u8).into()
+    }
+}
+
+/// channels are clamped to [0, 1] before quantizing
+impl From<srgb> for Rgb<u8> {
+    fn from(value: srgb) -> Self {
+        Rgb([value.r, value.g, value.b].map(bytes::to_u8))
+    }
+}
+
+impl From<Rgba<u8>> for srgba {
+    fn from(Rgba(channels): Rgba<u8>) -> Self {
+        channels.map(bytes::from_u8).into()
+    }
+}
+
+/// channels are clamped to [0, 1] before quantizing
+impl From<srgba> for Rgba<u8> {
+    fn from(value: srgba) -> Self {
+        Rgba(<[f32; 4]>::from(value).map(bytes::to_u8))
+    }
+}
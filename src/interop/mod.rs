@@ -0,0 +1,14 @@
+//! conversions to and from the color types of other crates, each behind its own feature
+
+#[cfg(feature = "approx")]
+mod approx;
+#[cfg(feature = "csscolorparser")]
+mod csscolorparser;
+#[cfg(feature = "egui")]
+mod egui;
+#[cfg(feature = "half")]
+mod half;
+#[cfg(feature = "image")]
+mod image;
+#[cfg(feature = "palette-interop")]
+mod palette;
@@ -0,0 +1,62 @@
+use crate::{oklab, oklch, rgb, srgb};
+
+impl From<::palette::Srgb> for srgb {
+    fn from(value: ::palette::Srgb) -> Self {
+        Self {
+            r: value.red,
+            g: value.green,
+            b: value.blue,
+        }
+    }
+}
+
+impl From<srgb> for ::palette::Srgb {
+    fn from(srgb { r, g, b }: srgb) -> Self {
+        Self::new(r, g, b)
+    }
+}
+
+impl From<::palette::LinSrgb> for rgb {
+    fn from(value: ::palette::LinSrgb) -> Self {
+        Self {
+            r: value.red,
+            g: value.green,
+            b: value.blue,
+        }
+    }
+}
+
+impl From<rgb> for ::palette::LinSrgb {
+    fn from(rgb { r, g, b }: rgb) -> Self {
+        Self::new(r, g, b)
+    }
+}
+
+impl From<::palette::Oklab> for oklab {
+    fn from(::palette::Oklab { l, a, b }: ::palette::Oklab) -> Self {
+        Self { l, a, b }
+    }
+}
+
+impl From<oklab> for ::palette::Oklab {
+    fn from(oklab { l, a, b }: oklab) -> Self {
+        Self::new(l, a, b)
+    }
+}
+
+// palette measures hue in degrees, where this crate uses turns
+impl From<::palette::Oklch> for oklch {
+    fn from(value: ::palette::Oklch) -> Self {
+        Self {
+            l: value.l,
+            c: value.chroma,
+            h: value.hue.into_positive_degrees() / 360.0,
+        }
+    }
+}
+
+impl From<oklch> for ::palette::Oklch {
+    fn from(oklch { l, c, h }: oklch) -> Self {
+        Self::new(l, c, h * 360.0)
+    }
+}
@@ -3,7 +3,7 @@
 //!
 //! # structs
 //!
-//! the structs in this module represent a color in a particular color space. all structs can be cast into each other, as well as casting to and from a [f32; 3]
+//! the structs in this module represent a color in a particular color space. all the three channel structs can be cast into each other, as well as casting to and from a [f32; 3]. the exceptions are [ycbcr], whose conversions need a matrix and a range (see [ycbcr::from_srgb]), and [srgba] and [cmyk], which have a fourth channel
 //!
 //! ```
 //! use tinycolors::srgb;
@@ -16,7 +16,7 @@
 //!
 //! # the color trait
 //!
-//! every color struct implements the color trait. the color trait ensures that every struct that implements it can be cast to all the colors. when writing functions that require a color, using a generic color allows the caller to store their colors in whatever format they want.
+//! every color struct except ycbcr, srgba, and cmyk implements the color trait. the color trait ensures that every struct that implements it can be cast to srgb, rgb, oklab, okhsl, okhsv, hsl, and hsv. when writing functions that require a color, using a generic color allows the caller to store their colors in whatever format they want.
 //!
 //! ```
 //! use tinycolors::{Color, srgb, rgb};
@@ -31,13 +31,240 @@
 //! ```
 //!
 //! > **_NOTE:_** conversions to and from okhsl and okhsv are thin wrappers for the `okhsl` crate. if that's all you're using, that crate might be a better choice for you.
+//!
+//! # conversions
+//!
+//! every conversion takes the shortest path through this graph, so each step is only paid once:
+//!
+//! ```text
+//! hsl, hsv, hsi ── srgb ── rgb ── oklab ── okhsl, okhsv, oklch
+//!                           │
+//!                   xyy ── xyz ── cielab ── cielch
+//! ```
+//!
+//! hsl, hsv, and hsi are defined on top of srgb, so reaching okhsl or okhsv from them means srgb → rgb → oklab first. okhsl and okhsv convert between each other through a single oklab value. round trips through okhsl and okhsv are accurate to about 1e-4, except along the pure blue edge of the srgb cube, where the `okhsl` crate's gamut approximation is off by up to 0.2
 
 use serde::{Deserialize, Serialize};
 
-/// any struct that implements this trait must implement Into for all color structs in this module
+mod alpha;
+mod ansi;
+pub mod as_hex;
+mod ase;
+mod blend;
+mod bytes;
+mod convert;
+mod css;
+mod cube;
+mod difference;
+mod edit;
+mod error;
+#[cfg(feature = "fixed")]
+mod fixed;
+mod gamut;
+mod gpl;
+mod gradient;
+mod hash;
+mod hex;
+mod hue;
+mod interop;
+mod light;
+mod luminance;
+mod lut;
+mod mix;
+mod names;
+mod ops;
+mod palette;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod precision;
+pub mod shader;
+mod stats;
+mod swatches;
+mod tailwind;
+mod tonemap;
+mod transfer;
+
+pub use alpha::Alpha;
+pub use ansi::ansi_pair;
+pub use ase::{AseError, parse_ase};
+pub use blend::BlendMode;
+pub use bytes::RoundMode;
+pub use css::{CssColor, parse_css_color};
+pub use cube::{CubeError, parse_cube};
+pub use edit::Edit;
+pub use error::ColorError;
+#[cfg(feature = "fixed")]
+pub use fixed::QColor;
+pub use gamut::{Intent, gamut_volume, hue_wheel};
+pub use gpl::{GplError, parse_gpl, to_gpl};
+pub use gradient::{Gradient, diverging, smoothstep};
+pub use hash::HashableColor;
+pub use hue::hue_distance;
+pub use luminance::{accessible_pair, most_readable, sort_by_luminance, sort_perceptual};
+pub use lut::Lut3D;
+pub use mix::{average, blend3, downsample_2x, mix_linear, steps, weighted_average};
+pub use names::named_colors;
+pub use palette::{
+    extract_palette, extract_palette_seeded, normalize_lightness, quantize_dithered,
+};
+#[cfg(feature = "rayon")]
+pub use parallel::par_convert;
+pub use precision::max_roundtrip_error;
+pub use stats::{ColorStats, color_stats};
+pub use swatches::{Swatches, palette_swatches};
+pub use tonemap::ToneMap;
+pub use transfer::SRGB_TO_LINEAR_LUT;
+
+/// any struct that implements this trait must be [Copy], convert to and from a [f32; 3], and implement Into for [srgb], [rgb], [oklab], [okhsl], [okhsv], [hsl], and [hsv]. the default methods are built on those conversions. the structs in this crate also convert into each other directly, but the trait doesn't require that
+///
+/// this is a breaking change for implementors outside this crate: `Copy`, `From<[f32; 3]>`, and `Into<[f32; 3]>` became supertraits when [Color::is_finite], [Color::sanitize], and [Color::try_into_checked] were added, so an impl that compiled before needs those three impls too
 pub trait Color:
-    Into<srgb> + Into<rgb> + Into<oklab> + Into<okhsl> + Into<okhsv> + Into<hsl> + Into<hsv>
+    Copy
+    + From<[f32; 3]>
+    + Into<[f32; 3]>
+    + Into<srgb>
+    + Into<rgb>
+    + Into<oklab>
+    + Into<okhsl>
+    + Into<okhsv>
+    + Into<hsl>
+    + Into<hsv>
 {
+    /// shorthand for `srgb::from(self)`, which reads better in method chains
+    fn to_srgb(self) -> srgb {
+        self.into()
+    }
+
+    /// shorthand for `rgb::from(self)`, which reads better in method chains
+    fn to_rgb(self) -> rgb {
+        self.into()
+    }
+
+    /// shorthand for `oklab::from(self)`, which reads better in method chains
+    fn to_oklab(self) -> oklab {
+        self.into()
+    }
+
+    /// shorthand for `okhsl::from(self)`, which reads better in method chains
+    fn to_okhsl(self) -> okhsl {
+        self.into()
+    }
+
+    /// shorthand for `okhsv::from(self)`, which reads better in method chains
+    fn to_okhsv(self) -> okhsv {
+        self.into()
+    }
+
+    /// shorthand for `hsl::from(self)`, which reads better in method chains
+    fn to_hsl(self) -> hsl {
+        self.into()
+    }
+
+    /// shorthand for `hsv::from(self)`, which reads better in method chains
+    fn to_hsv(self) -> hsv {
+        self.into()
+    }
+
+    /// converts to any color the type converts into, named with a turbofish so it chains without type annotations
+    ///
+    /// ```
+    /// use tinycolors::{Color, oklab, srgb};
+    ///
+    /// let lightness = srgb::RED.convert::<oklab>().l;
+    /// ```
+    fn convert<T>(self) -> T
+    where
+        Self: Into<T>,
+    {
+        self.into()
+    }
+
+    /// returns true if no channel is NaN or infinite
+    fn is_finite(&self) -> bool {
+        let channels: [f32; 3] = (*self).into();
+        channels.iter().all(|c| c.is_finite())
+    }
+
+    /// replaces every NaN or infinite channel with 0.0
+    fn sanitize(self) -> Self {
+        let channels: [f32; 3] = self.into();
+        channels.map(|c| if c.is_finite() { c } else { 0.0 }).into()
+    }
+
+    /// converts the color, failing if either the color or the result of the conversion has a NaN or infinite channel
+    ///
+    /// ```
+    /// use tinycolors::{Color, ColorError, oklab, srgb};
+    ///
+    /// let color = srgb { r: f32::NAN, g: 0.5, b: 0.5 };
+    /// assert_eq!(color.try_into_checked::<oklab>(), Err(ColorError::NonFinite));
+    /// ```
+    fn try_into_checked<T>(self) -> Result<T, ColorError>
+    where
+        Self: Into<T>,
+        T: Color,
+    {
+        if !self.is_finite() {
+            return Err(ColorError::NonFinite);
+        }
+
+        let converted: T = self.into();
+        if converted.is_finite() {
+            Ok(converted)
+        } else {
+            Err(ColorError::NonFinite)
+        }
+    }
+
+    /// rounds every channel to `decimals` decimal places. hue is a channel like any other, so in the cylindrical spaces it's rounded in turns, not degrees
+    fn round_to(self, decimals: u32) -> Self {
+        let scale = 10f32.powi(decimals as i32);
+        let channels: [f32; 3] = self.into();
+        channels.map(|c| (c * scale).round() / scale).into()
+    }
+
+    /// clamps every channel to [min, max]. for the cylindrical spaces, [hsl::wrap_hue] and friends are usually what you want for the hue
+    ///
+    /// # Panics
+    ///
+    /// panics if `min` is greater than `max`, or either is NaN, like [f32::clamp]
+    fn clamp(self, min: f32, max: f32) -> Self {
+        let channels: [f32; 3] = self.into();
+        channels.map(|c| c.clamp(min, max)).into()
+    }
+
+    /// [Color::sanitize] in place
+    fn sanitize_mut(&mut self) {
+        *self = self.sanitize();
+    }
+
+    /// [Color::clamp] in place
+    ///
+    /// # Panics
+    ///
+    /// panics if `min` is greater than `max`, or either is NaN, like [f32::clamp]
+    fn clamp_mut(&mut self, min: f32, max: f32) {
+        *self = self.clamp(min, max);
+    }
+
+    /// interpolates from `self` at `t` = 0 to `other` at `t` = 1, channel by channel. `t` isn't clamped, so values outside of [0, 1] extrapolate
+    ///
+    /// a channel by channel lerp goes the long way around the hue wheel whenever the hues are more than half a turn apart, so the cylindrical spaces override this to take the hue the short way instead, and any color type with a hue should do the same
+    fn lerp(self, other: Self, t: f32) -> Self {
+        let (a, b): ([f32; 3], [f32; 3]) = (self.into(), other.into());
+        [0, 1, 2].map(|i| a[i] + (b[i] - a[i]) * t).into()
+    }
+
+    /// starts a chain of adjustments, see [Edit]
+    fn edit(self) -> Edit {
+        Edit::new(self)
+    }
+
+    /// returns true if the color's oklch chroma is below `tolerance`. it's measured in oklab whatever space the color is in, so every type agrees on what counts as gray. 0.02 is about the point where a tint becomes noticeable
+    fn is_gray(&self, tolerance: f32) -> bool {
+        let oklab { a, b, .. } = (*self).into();
+        (a * a + b * b).sqrt() < tolerance
+    }
 }
 
 /// a color in the srgb color space
@@ -97,6 +324,46 @@ impl srgb {
         g: 0.0,
         b: 1.0,
     };
+
+    /// a gray with every channel set to `v`. it's a const fn, so it works for constants
+    ///
+    /// ```
+    /// use tinycolors::srgb;
+    ///
+    /// const MID: srgb = srgb::gray(0.5);
+    /// assert_eq!(MID, srgb::from([0.5; 3]));
+    /// ```
+    pub const fn gray(v: f32) -> srgb {
+        srgb { r: v, g: v, b: v }
+    }
+
+    /// how far outside of [0, 1] a channel may land before [srgb::clipping_channels] reports it
+    pub const CLIP_EPSILON: f32 = 1e-5;
+
+    /// returns true if every channel is within [0, 1]. a NaN channel is out of gamut
+    pub fn is_in_gamut(&self) -> bool {
+        self.gamut_excess() == 0.0
+    }
+
+    /// returns how far the worst channel lies outside of [0, 1], or 0.0 if the color is in gamut. a NaN or infinite channel gives infinity, since [f32::max] would otherwise drop the NaN
+    pub fn gamut_excess(&self) -> f32 {
+        [self.r, self.g, self.b]
+            .into_iter()
+            .map(|c| {
+                if c.is_finite() {
+                    f32::max(c - 1.0, -c)
+                } else {
+                    f32::INFINITY
+                }
+            })
+            .fold(0.0, f32::max)
+    }
+
+    /// which channels lie outside of [0, 1], as `[r, g, b]`, for marking clipped pixels. channels within [srgb::CLIP_EPSILON] of the range don't count, so rounding in the conversions doesn't flag them
+    pub fn clipping_channels(&self) -> [bool; 3] {
+        [self.r, self.g, self.b]
+            .map(|c| !(-Self::CLIP_EPSILON..=1.0 + Self::CLIP_EPSILON).contains(&c))
+    }
 }
 impl Color for srgb {}
 
@@ -116,6 +383,32 @@ impl From<srgb> for [f32; 3] {
     }
 }
 
+impl From<(f32, f32, f32)> for srgb {
+    fn from((a, b, c): (f32, f32, f32)) -> Self {
+        [a, b, c].into()
+    }
+}
+
+impl From<srgb> for (f32, f32, f32) {
+    fn from(value: srgb) -> Self {
+        let [a, b, c] = value.into();
+        (a, b, c)
+    }
+}
+
+impl TryFrom<&[f32]> for srgb {
+    type Error = ColorError;
+
+    fn try_from(value: &[f32]) -> Result<Self, ColorError> {
+        <[f32; 3]>::try_from(value)
+            .map(Self::from)
+            .map_err(|_| ColorError::WrongLength {
+                expected: 3,
+                found: value.len(),
+            })
+    }
+}
+
 impl From<rgb> for srgb {
     fn from(value: rgb) -> Self {
         Self {
@@ -253,6 +546,63 @@ impl From<hsv> for srgb {
     }
 }
 
+impl From<xyz> for srgb {
+    fn from(value: xyz) -> Self {
+        rgb::from(value).into()
+    }
+}
+
+impl From<cielab> for srgb {
+    fn from(value: cielab) -> Self {
+        rgb::from(value).into()
+    }
+}
+
+impl From<cielch> for srgb {
+    fn from(value: cielch) -> Self {
+        rgb::from(value).into()
+    }
+}
+
+impl From<hsi> for srgb {
+    fn from(hsi { h, s, i }: hsi) -> Self {
+        // each 120° sector pins one channel to the minimum and derives the next one from the hue
+        fn sector(h: f32, s: f32, i: f32) -> (f32, f32, f32) {
+            let h = h * std::f32::consts::TAU;
+            let min = i * (1.0 - s);
+            let lead = i * (1.0 + s * h.cos() / (std::f32::consts::FRAC_PI_3 - h).cos());
+            let trail = 3.0 * i - (min + lead);
+
+            (lead, trail, min)
+        }
+
+        let h = h.rem_euclid(1.0);
+
+        if h < 1.0 / 3.0 {
+            let (r, g, b) = sector(h, s, i);
+            Self { r, g, b }
+        } else if h < 2.0 / 3.0 {
+            let (g, b, r) = sector(h - 1.0 / 3.0, s, i);
+            Self { r, g, b }
+        } else {
+            let (b, r, g) = sector(h - 2.0 / 3.0, s, i);
+            Self { r, g, b }
+        }
+    }
+}
+
+impl From<oklch> for srgb {
+    fn from(value: oklch) -> Self {
+        oklab::from(value).into()
+    }
+}
+
+impl From<xyy> for srgb {
+    fn from(value: xyy) -> Self {
+        xyz::from(value).into()
+    }
+}
+
 /// a color in the linear rgb color space
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
@@ -263,21 +613,37 @@ pub struct rgb {
 }
 
 impl rgb {
+    /// a gray with every channel set to `v`. it's a const fn, so it works for constants
+    ///
+    /// ```
+    /// use tinycolors::rgb;
+    ///
+    /// const MID: rgb = rgb::gray(0.18);
+    /// assert_eq!(MID, rgb::from([0.18; 3]));
+    /// ```
+    pub const fn gray(v: f32) -> rgb {
+        rgb { r: v, g: v, b: v }
+    }
+
     fn from_linear(x: f32) -> f32 {
+        use transfer::{ENCODE_THRESHOLD, EXPONENT, LINEAR_SLOPE, OFFSET, SCALE};
+
         let x = x as f64;
-        if x >= 0.0031308 {
-            ((1.055) * x.powf(1.0 / 2.4) - 0.055) as f32
+        if x >= ENCODE_THRESHOLD {
+            (SCALE * x.powf(1.0 / EXPONENT) - OFFSET) as f32
         } else {
-            (12.92 * x) as f32
+            (LINEAR_SLOPE * x) as f32
         }
     }
 
     fn to_linear(x: f32) -> f32 {
+        use transfer::{DECODE_THRESHOLD, EXPONENT, LINEAR_SLOPE, OFFSET, SCALE};
+
         let x = x as f64;
-        if x >= 0.04045 {
-            (((x + 0.055) / (1.055)).powf(2.4)) as f32
+        if x >= DECODE_THRESHOLD {
+            ((x + OFFSET) / SCALE).powf(EXPONENT) as f32
         } else {
-            (x / 12.92) as f32
+            (x / LINEAR_SLOPE) as f32
         }
     }
 }
@@ -299,6 +665,32 @@ impl From<rgb> for [f32; 3] {
     }
 }
 
+impl From<(f32, f32, f32)> for rgb {
+    fn from((a, b, c): (f32, f32, f32)) -> Self {
+        [a, b, c].into()
+    }
+}
+
+impl From<rgb> for (f32, f32, f32) {
+    fn from(value: rgb) -> Self {
+        let [a, b, c] = value.into();
+        (a, b, c)
+    }
+}
+
+impl TryFrom<&[f32]> for rgb {
+    type Error = ColorError;
+
+    fn try_from(value: &[f32]) -> Result<Self, ColorError> {
+        <[f32; 3]>::try_from(value)
+            .map(Self::from)
+            .map_err(|_| ColorError::WrongLength {
+                expected: 3,
+                found: value.len(),
+            })
+    }
+}
+
 impl From<srgb> for rgb {
     fn from(value: srgb) -> Self {
         Self {
@@ -311,19 +703,8 @@ impl From<srgb> for rgb {
 
 impl From<oklab> for rgb {
     fn from(value: oklab) -> Self {
-        let l_ = value.l + 0.3963377774 * value.a + 0.2158037573 * value.b;
-        let m_ = value.l - 0.1055613458 * value.a - 0.0638541728 * value.b;
-        let s_ = value.l - 0.0894841775 * value.a - 1.2914855480 * value.b;
-
-        let l = l_ * l_ * l_;
-        let m = m_ * m_ * m_;
-        let s = s_ * s_ * s_;
-
-        Self {
-            r: 4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
-            g: -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
-            b: -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s,
-        }
+        let lms = oklab::multiply(&oklab::M2_INV, value.into());
+        oklab::multiply(&oklab::M1_INV, lms.map(|c| c * c * c)).into()
     }
 }
 
@@ -351,6 +732,46 @@ impl From<hsv> for rgb {
     }
 }
 
+impl From<xyz> for rgb {
+    fn from(xyz { x, y, z }: xyz) -> Self {
+        Self {
+            r: 3.2404542 * x - 1.5371385 * y - 0.4985314 * z,
+            g: -0.9692660 * x + 1.8760108 * y + 0.0415560 * z,
+            b: 0.0556434 * x - 0.2040259 * y + 1.0572252 * z,
+        }
+    }
+}
+
+impl From<cielab> for rgb {
+    fn from(value: cielab) -> Self {
+        xyz::from(value).into()
+    }
+}
+
+impl From<cielch> for rgb {
+    fn from(value: cielch) -> Self {
+        xyz::from(value).into()
+    }
+}
+
+impl From<hsi> for rgb {
+    fn from(value: hsi) -> Self {
+        srgb::from(value).into()
+    }
+}
+
+impl From<oklch> for rgb {
+    fn from(value: oklch) -> Self {
+        oklab::from(value).into()
+    }
+}
+
+impl From<xyy> for rgb {
+    fn from(value: xyy) -> Self {
+        xyz::from(value).into()
+    }
+}
+
 /// a color in the oklab color space
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
@@ -359,6 +780,39 @@ pub struct oklab {
     pub a: f32,
     pub b: f32,
 }
+impl oklab {
+    /// linear rgb to lms cone responses, the first step from rgb to oklab. row major, so each row gives one of l, m, and s
+    pub const M1: [[f32; 3]; 3] = [
+        [0.4122214708, 0.5363325363, 0.0514459929],
+        [0.2119034982, 0.6806995451, 0.1073969566],
+        [0.0883024619, 0.2817188376, 0.6299787005],
+    ];
+
+    /// cube rooted lms to oklab, the second step from rgb to oklab. row major, so each row gives one of l, a, and b
+    pub const M2: [[f32; 3]; 3] = [
+        [0.2104542553, 0.7936177850, -0.0040720468],
+        [1.9779984951, -2.4285922050, 0.4505937099],
+        [0.0259040371, 0.7827717662, -0.8086757660],
+    ];
+
+    /// the inverse of [oklab::M2], from oklab to cube rooted lms
+    pub const M2_INV: [[f32; 3]; 3] = [
+        [1.0, 0.3963377774, 0.2158037573],
+        [1.0, -0.1055613458, -0.0638541728],
+        [1.0, -0.0894841775, -1.2914855480],
+    ];
+
+    /// the inverse of [oklab::M1], from lms to linear rgb
+    pub const M1_INV: [[f32; 3]; 3] = [
+        [4.0767416621, -3.3077115913, 0.2309699292],
+        [-1.2684380046, 2.6097574011, -0.3413193965],
+        [-0.0041960863, -0.7034186147, 1.7076147010],
+    ];
+
+    fn multiply(matrix: &[[f32; 3]; 3], [x, y, z]: [f32; 3]) -> [f32; 3] {
+        matrix.map(|row| row[0] * x + row[1] * y + row[2] * z)
+    }
+}
 impl Color for oklab {}
 
 impl From<[f32; 3]> for oklab {
@@ -377,21 +831,36 @@ impl From<oklab> for [f32; 3] {
     }
 }
 
-impl From<rgb> for oklab {
-    fn from(value: rgb) -> Self {
-        let l = 0.4122214708 * value.r + 0.5363325363 * value.g + 0.051445;
-        let m = 0.2119034982 * value.r + 0.6806995451 * value.g + 0.107396;
-        let s = 0.0883024619 * value.r + 0.2817188376 * value.g + 0.629978;
+impl From<(f32, f32, f32)> for oklab {
+    fn from((a, b, c): (f32, f32, f32)) -> Self {
+        [a, b, c].into()
+    }
+}
 
-        let l_ = f32::cbrt(l);
-        let m_ = f32::cbrt(m);
-        let s_ = f32::cbrt(s);
+impl From<oklab> for (f32, f32, f32) {
+    fn from(value: oklab) -> Self {
+        let [a, b, c] = value.into();
+        (a, b, c)
+    }
+}
 
-        Self {
-            l: 0.2104542553 * l_ + 0.7936177850 * m_ - 0.0040720468 * s_,
-            a: 1.9779984951 * l_ - 2.4285922050 * m_ + 0.4505937099 * s_,
-            b: 0.0259040371 * l_ + 0.7827717662 * m_ - 0.8086757660 * s_,
-        }
+impl TryFrom<&[f32]> for oklab {
+    type Error = ColorError;
+
+    fn try_from(value: &[f32]) -> Result<Self, ColorError> {
+        <[f32; 3]>::try_from(value)
+            .map(Self::from)
+            .map_err(|_| ColorError::WrongLength {
+                expected: 3,
+                found: value.len(),
+            })
+    }
+}
+
+impl From<rgb> for oklab {
+    fn from(value: rgb) -> Self {
+        let lms = oklab::multiply(&oklab::M1, value.into());
+        oklab::multiply(&oklab::M2, lms.map(f32::cbrt)).into()
     }
 }
 
@@ -431,6 +900,60 @@ impl From<hsv> for oklab {
     }
 }
 
+impl From<xyz> for oklab {
+    fn from(value: xyz) -> Self {
+        rgb::from(value).into()
+    }
+}
+
+impl From<cielab> for oklab {
+    fn from(value: cielab) -> Self {
+        rgb::from(value).into()
+    }
+}
+
+impl From<cielch> for oklab {
+    fn from(value: cielch) -> Self {
+        rgb::from(value).into()
+    }
+}
+
+impl From<hsi> for oklab {
+    fn from(value: hsi) -> Self {
+        srgb::from(value).into()
+    }
+}
+
+impl From<oklch> for oklab {
+    fn from(oklch { l, c, h }: oklch) -> Self {
+        let (sin, cos) = (h * std::f32::consts::TAU).sin_cos();
+
+        Self {
+            l,
+            a: c * cos,
+            b: c * sin,
+        }
+    }
+}
+
+impl From<xyy> for oklab {
+    fn from(value: xyy) -> Self {
+        xyz::from(value).into()
+    }
+}
+
+impl From<::okhsl::Oklab> for oklab {
+    fn from(::okhsl::Oklab { l, a, b }: ::okhsl::Oklab) -> Self {
+        Self { l, a, b }
+    }
+}
+
+impl From<oklab> for ::okhsl::Oklab {
+    fn from(oklab { l, a, b }: oklab) -> Self {
+        Self { l, a, b }
+    }
+}
+
 /// a color in the okhsl color space
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
@@ -439,7 +962,26 @@ pub struct okhsl {
     pub s: f32,
     pub l: f32,
 }
-impl Color for okhsl {}
+impl okhsl {
+    /// the toe that okhsl and okhsv apply to oklab lightness, so that it matches cielab lightness more closely in the shadows
+    fn toe(x: f32) -> f32 {
+        const K1: f32 = 0.206;
+        const K2: f32 = 0.03;
+        const K3: f32 = (1.0 + K1) / (1.0 + K2);
+
+        0.5 * (K3 * x - K1 + ((K3 * x - K1) * (K3 * x - K1) + 4.0 * K2 * K3 * x).sqrt())
+    }
+}
+impl Color for okhsl {
+    /// like the default, but the hue takes the short way around the wheel and comes out wrapped into [0, 1)
+    fn lerp(self, other: Self, t: f32) -> Self {
+        Self {
+            h: hue::lerp_hue(self.h, other.h, t),
+            s: self.s + (other.s - self.s) * t,
+            l: self.l + (other.l - self.l) * t,
+        }
+    }
+}
 
 impl From<[f32; 3]> for okhsl {
     fn from(value: [f32; 3]) -> Self {
@@ -457,6 +999,32 @@ impl From<okhsl> for [f32; 3] {
     }
 }
 
+impl From<(f32, f32, f32)> for okhsl {
+    fn from((a, b, c): (f32, f32, f32)) -> Self {
+        [a, b, c].into()
+    }
+}
+
+impl From<okhsl> for (f32, f32, f32) {
+    fn from(value: okhsl) -> Self {
+        let [a, b, c] = value.into();
+        (a, b, c)
+    }
+}
+
+impl TryFrom<&[f32]> for okhsl {
+    type Error = ColorError;
+
+    fn try_from(value: &[f32]) -> Result<Self, ColorError> {
+        <[f32; 3]>::try_from(value)
+            .map(Self::from)
+            .map_err(|_| ColorError::WrongLength {
+                expected: 3,
+                found: value.len(),
+            })
+    }
+}
+
 impl From<srgb> for okhsl {
     fn from(value: srgb) -> Self {
         oklab::from(value).into()
@@ -471,15 +1039,25 @@ impl From<rgb> for okhsl {
 
 impl From<oklab> for okhsl {
     fn from(oklab { l, a, b }: oklab) -> Self {
+        // the okhsl crate returns grays without applying the toe, which the way back does undo
+        if a == 0.0 && b == 0.0 && l > 0.0 && l < 1.0 {
+            return Self {
+                h: 0.0,
+                s: 0.0,
+                l: okhsl::toe(l),
+            };
+        }
+
         let ::okhsl::Okhsl { h, s, l } = ::okhsl::oklab_to_okhsl(::okhsl::Oklab { l, a, b });
 
         Self { h: h as f32, s, l }
     }
 }
 
+/// the okhsl crate has no direct path between the two, so this goes through a single oklab value and never touches srgb
 impl From<okhsv> for okhsl {
     fn from(value: okhsv) -> Self {
-        oklab::from(value).into()
+        ::okhsl::oklab_to_okhsl(::okhsl::okhsv_to_oklab(value.into())).into()
     }
 }
 
@@ -495,6 +1073,54 @@ impl From<hsv> for okhsl {
     }
 }
 
+impl From<xyz> for okhsl {
+    fn from(value: xyz) -> Self {
+        oklab::from(value).into()
+    }
+}
+
+impl From<cielab> for okhsl {
+    fn from(value: cielab) -> Self {
+        oklab::from(value).into()
+    }
+}
+
+impl From<cielch> for okhsl {
+    fn from(value: cielch) -> Self {
+        oklab::from(value).into()
+    }
+}
+
+impl From<hsi> for okhsl {
+    fn from(value: hsi) -> Self {
+        oklab::from(value).into()
+    }
+}
+
+impl From<oklch> for okhsl {
+    fn from(value: oklch) -> Self {
+        oklab::from(value).into()
+    }
+}
+
+impl From<xyy> for okhsl {
+    fn from(value: xyy) -> Self {
+        xyz::from(value).into()
+    }
+}
+
+impl From<::okhsl::Okhsl> for okhsl {
+    fn from(::okhsl::Okhsl { h, s, l }: ::okhsl::Okhsl) -> Self {
+        Self { h: h as f32, s, l }
+    }
+}
+
+impl From<okhsl> for ::okhsl::Okhsl {
+    fn from(okhsl { h, s, l }: okhsl) -> Self {
+        Self { h: h as f64, s, l }
+    }
+}
+
 /// a color in the okhsv color space
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
@@ -503,7 +1129,16 @@ pub struct okhsv {
     pub s: f32,
     pub v: f32,
 }
-impl Color for okhsv {}
+impl Color for okhsv {
+    /// like the default, but the hue takes the short way around the wheel and comes out wrapped into [0, 1)
+    fn lerp(self, other: Self, t: f32) -> Self {
+        Self {
+            h: hue::lerp_hue(self.h, other.h, t),
+            s: self.s + (other.s - self.s) * t,
+            v: self.v + (other.v - self.v) * t,
+        }
+    }
+}
 
 impl From<[f32; 3]> for okhsv {
     fn from(value: [f32; 3]) -> Self {
@@ -521,6 +1156,32 @@ impl From<okhsv> for [f32; 3] {
     }
 }
 
+impl From<(f32, f32, f32)> for okhsv {
+    fn from((a, b, c): (f32, f32, f32)) -> Self {
+        [a, b, c].into()
+    }
+}
+
+impl From<okhsv> for (f32, f32, f32) {
+    fn from(value: okhsv) -> Self {
+        let [a, b, c] = value.into();
+        (a, b, c)
+    }
+}
+
+impl TryFrom<&[f32]> for okhsv {
+    type Error = ColorError;
+
+    fn try_from(value: &[f32]) -> Result<Self, ColorError> {
+        <[f32; 3]>::try_from(value)
+            .map(Self::from)
+            .map_err(|_| ColorError::WrongLength {
+                expected: 3,
+                found: value.len(),
+            })
+    }
+}
+
 impl From<srgb> for okhsv {
     fn from(value: srgb) -> Self {
         oklab::from(value).into()
@@ -535,15 +1196,25 @@ impl From<rgb> for okhsv {
 
 impl From<oklab> for okhsv {
     fn from(oklab { l, a, b }: oklab) -> Self {
+        // the okhsl crate returns grays without applying the toe, which the way back does undo
+        if a == 0.0 && b == 0.0 && l > 0.0 && l < 1.0 {
+            return Self {
+                h: 0.0,
+                s: 0.0,
+                v: okhsl::toe(l),
+            };
+        }
+
         let ::okhsl::Okhsv { h, s, v } = ::okhsl::oklab_to_okhsv(::okhsl::Oklab { l, a, b });
 
         Self { h: h as f32, s, v }
     }
 }
 
+/// the okhsl crate has no direct path between the two, so this goes through a single oklab value and never touches srgb
 impl From<okhsl> for okhsv {
     fn from(value: okhsl) -> Self {
-        oklab::from(value).into()
+        ::okhsl::oklab_to_okhsv(::okhsl::okhsl_to_oklab(value.into())).into()
     }
 }
 
@@ -559,6 +1230,54 @@ impl From<hsv> for okhsv {
     }
 }
 
+impl From<xyz> for okhsv {
+    fn from(value: xyz) -> Self {
+        oklab::from(value).into()
+    }
+}
+
+impl From<cielab> for okhsv {
+    fn from(value: cielab) -> Self {
+        oklab::from(value).into()
+    }
+}
+
+impl From<cielch> for okhsv {
+    fn from(value: cielch) -> Self {
+        oklab::from(value).into()
+    }
+}
+
+impl From<hsi> for okhsv {
+    fn from(value: hsi) -> Self {
+        oklab::from(value).into()
+    }
+}
+
+impl From<oklch> for okhsv {
+    fn from(value: oklch) -> Self {
+        oklab::from(value).into()
+    }
+}
+
+impl From<xyy> for okhsv {
+    fn from(value: xyy) -> Self {
+        xyz::from(value).into()
+    }
+}
+
+impl From<::okhsl::Okhsv> for okhsv {
+    fn from(::okhsl::Okhsv { h, s, v }: ::okhsl::Okhsv) -> Self {
+        Self { h: h as f32, s, v }
+    }
+}
+
+impl From<okhsv> for ::okhsl::Okhsv {
+    fn from(okhsv { h, s, v }: okhsv) -> Self {
+        Self { h: h as f64, s, v }
+    }
+}
+
 /// a color in the hsl color space
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
@@ -567,7 +1286,16 @@ pub struct hsl {
     pub s: f32,
     pub l: f32,
 }
-impl Color for hsl {}
+impl Color for hsl {
+    /// like the default, but the hue takes the short way around the wheel and comes out wrapped into [0, 1)
+    fn lerp(self, other: Self, t: f32) -> Self {
+        Self {
+            h: hue::lerp_hue(self.h, other.h, t),
+            s: self.s + (other.s - self.s) * t,
+            l: self.l + (other.l - self.l) * t,
+        }
+    }
+}
 
 impl From<[f32; 3]> for hsl {
     fn from(value: [f32; 3]) -> Self {
@@ -585,6 +1313,32 @@ impl From<hsl> for [f32; 3] {
     }
 }
 
+impl From<(f32, f32, f32)> for hsl {
+    fn from((a, b, c): (f32, f32, f32)) -> Self {
+        [a, b, c].into()
+    }
+}
+
+impl From<hsl> for (f32, f32, f32) {
+    fn from(value: hsl) -> Self {
+        let [a, b, c] = value.into();
+        (a, b, c)
+    }
+}
+
+impl TryFrom<&[f32]> for hsl {
+    type Error = ColorError;
+
+    fn try_from(value: &[f32]) -> Result<Self, ColorError> {
+        <[f32; 3]>::try_from(value)
+            .map(Self::from)
+            .map_err(|_| ColorError::WrongLength {
+                expected: 3,
+                found: value.len(),
+            })
+    }
+}
+
 impl From<srgb> for hsl {
     fn from(value: srgb) -> Self {
         let r = value.r;
@@ -656,6 +1410,58 @@ impl From<hsv> for hsl {
     }
 }
 
+impl From<xyz> for hsl {
+    fn from(value: xyz) -> Self {
+        srgb::from(value).into()
+    }
+}
+
+impl From<cielab> for hsl {
+    fn from(value: cielab) -> Self {
+        srgb::from(value).into()
+    }
+}
+
+impl From<cielch> for hsl {
+    fn from(value: cielch) -> Self {
+        srgb::from(value).into()
+    }
+}
+
+impl From<hsi> for hsl {
+    fn from(value: hsi) -> Self {
+        srgb::from(value).into()
+    }
+}
+
+impl From<oklch> for hsl {
+    fn from(value: oklch) -> Self {
+        srgb::from(value).into()
+    }
+}
+
+impl From<xyy> for hsl {
+    fn from(value: xyy) -> Self {
+        srgb::from(value).into()
+    }
+}
+
+impl hsl {
+    /// builds a color from a hue in degrees and saturation and lightness in percent, the way design tools usually show them
+    pub fn from_degrees(h: f32, s_percent: f32, l_percent: f32) -> Self {
+        Self {
+            h: h / 360.0,
+            s: s_percent / 100.0,
+            l: l_percent / 100.0,
+        }
+    }
+
+    /// the hue in degrees and saturation and lightness in percent. the inverse of [hsl::from_degrees]
+    pub fn to_degrees(self) -> (f32, f32, f32) {
+        (self.h * 360.0, self.s * 100.0, self.l * 100.0)
+    }
+}
+
 /// a color in the hsv color space
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
@@ -664,7 +1470,16 @@ pub struct hsv {
     pub s: f32,
     pub v: f32,
 }
-impl Color for hsv {}
+impl Color for hsv {
+    /// like the default, but the hue takes the short way around the wheel and comes out wrapped into [0, 1)
+    fn lerp(self, other: Self, t: f32) -> Self {
+        Self {
+            h: hue::lerp_hue(self.h, other.h, t),
+            s: self.s + (other.s - self.s) * t,
+            v: self.v + (other.v - self.v) * t,
+        }
+    }
+}
 
 impl From<[f32; 3]> for hsv {
     fn from(value: [f32; 3]) -> Self {
@@ -682,6 +1497,32 @@ impl From<hsv> for [f32; 3] {
     }
 }
 
+impl From<(f32, f32, f32)> for hsv {
+    fn from((a, b, c): (f32, f32, f32)) -> Self {
+        [a, b, c].into()
+    }
+}
+
+impl From<hsv> for (f32, f32, f32) {
+    fn from(value: hsv) -> Self {
+        let [a, b, c] = value.into();
+        (a, b, c)
+    }
+}
+
+impl TryFrom<&[f32]> for hsv {
+    type Error = ColorError;
+
+    fn try_from(value: &[f32]) -> Result<Self, ColorError> {
+        <[f32; 3]>::try_from(value)
+            .map(Self::from)
+            .map_err(|_| ColorError::WrongLength {
+                expected: 3,
+                found: value.len(),
+            })
+    }
+}
+
 impl From<srgb> for hsv {
     fn from(value: srgb) -> Self {
         let r = value.r;
@@ -744,3 +1585,1363 @@ impl From<hsl> for hsv {
         srgb::from(value).into()
     }
 }
+
+impl From<xyz> for hsv {
+    fn from(value: xyz) -> Self {
+        srgb::from(value).into()
+    }
+}
+
+impl From<cielab> for hsv {
+    fn from(value: cielab) -> Self {
+        srgb::from(value).into()
+    }
+}
+
+impl From<cielch> for hsv {
+    fn from(value: cielch) -> Self {
+        srgb::from(value).into()
+    }
+}
+
+impl From<hsi> for hsv {
+    fn from(value: hsi) -> Self {
+        srgb::from(value).into()
+    }
+}
+
+impl From<oklch> for hsv {
+    fn from(value: oklch) -> Self {
+        srgb::from(value).into()
+    }
+}
+
+impl From<xyy> for hsv {
+    fn from(value: xyy) -> Self {
+        srgb::from(value).into()
+    }
+}
+
+impl hsv {
+    /// builds a color from a hue in degrees and saturation and value in percent, the way design tools usually show them
+    pub fn from_degrees(h: f32, s_percent: f32, v_percent: f32) -> Self {
+        Self {
+            h: h / 360.0,
+            s: s_percent / 100.0,
+            v: v_percent / 100.0,
+        }
+    }
+
+    /// the hue in degrees and saturation and value in percent. the inverse of [hsv::from_degrees]
+    pub fn to_degrees(self) -> (f32, f32, f32) {
+        (self.h * 360.0, self.s * 100.0, self.v * 100.0)
+    }
+}
+
+/// a color in the cie 1931 xyz color space, relative to the d65 white point. `y` is relative luminance, with white at 1.0
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct xyz {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl xyz {
+    /// the d65 reference white
+    pub const D65: xyz = xyz {
+        x: 0.95047,
+        y: 1.0,
+        z: 1.08883,
+    };
+
+    /// the d50 reference white used by icc profiles
+    pub const D50: xyz = xyz {
+        x: 0.96422,
+        y: 1.0,
+        z: 0.82521,
+    };
+
+    /// bradford chromatic adaptation from d65 to d50, row major
+    const BRADFORD_D65_TO_D50: [[f32; 3]; 3] = [
+        [1.0478112, 0.0228866, -0.0501270],
+        [0.0295424, 0.9904844, -0.0170491],
+        [-0.0092345, 0.0150436, 0.7521316],
+    ];
+
+    /// bradford chromatic adaptation from d50 to d65, row major
+    const BRADFORD_D50_TO_D65: [[f32; 3]; 3] = [
+        [0.9555766, -0.0230393, 0.0631636],
+        [-0.0282895, 1.0099416, 0.0210077],
+        [0.0122982, -0.0204830, 1.3299098],
+    ];
+
+    /// adapts the color to the d50 white point with the bradford transform, the way icc tools do. the result is relative to d50, so don't feed it back into the other conversions, which expect d65
+    pub fn to_d50(self) -> xyz {
+        let [x, y, z]: [f32; 3] = self.into();
+        xyz::BRADFORD_D65_TO_D50
+            .map(|row| row[0] * x + row[1] * y + row[2] * z)
+            .into()
+    }
+
+    /// adapts a d50 relative color back to d65, the inverse of [xyz::to_d50]
+    pub fn from_d50(d50: xyz) -> xyz {
+        let [x, y, z]: [f32; 3] = d50.into();
+        xyz::BRADFORD_D50_TO_D65
+            .map(|row| row[0] * x + row[1] * y + row[2] * z)
+            .into()
+    }
+
+    /// converts to cielab relative to d50 instead of d65, matching the lab values in icc profiles and pdf workflows
+    pub fn to_cielab_d50(self) -> cielab {
+        cielab::from_xyz_with_white(self.to_d50(), xyz::D50)
+    }
+}
+impl Color for xyz {}
+
+impl From<[f32; 3]> for xyz {
+    fn from(value: [f32; 3]) -> Self {
+        Self {
+            x: value[0],
+            y: value[1],
+            z: value[2],
+        }
+    }
+}
+
+impl From<xyz> for [f32; 3] {
+    fn from(value: xyz) -> Self {
+        [value.x, value.y, value.z]
+    }
+}
+
+impl From<(f32, f32, f32)> for xyz {
+    fn from((a, b, c): (f32, f32, f32)) -> Self {
+        [a, b, c].into()
+    }
+}
+
+impl From<xyz> for (f32, f32, f32) {
+    fn from(value: xyz) -> Self {
+        let [a, b, c] = value.into();
+        (a, b, c)
+    }
+}
+
+impl TryFrom<&[f32]> for xyz {
+    type Error = ColorError;
+
+    fn try_from(value: &[f32]) -> Result<Self, ColorError> {
+        <[f32; 3]>::try_from(value)
+            .map(Self::from)
+            .map_err(|_| ColorError::WrongLength {
+                expected: 3,
+                found: value.len(),
+            })
+    }
+}
+
+impl From<srgb> for xyz {
+    fn from(value: srgb) -> Self {
+        rgb::from(value).into()
+    }
+}
+
+impl From<rgb> for xyz {
+    fn from(rgb { r, g, b }: rgb) -> Self {
+        Self {
+            x: 0.4124564 * r + 0.3575761 * g + 0.1804375 * b,
+            y: 0.2126729 * r + 0.7151522 * g + 0.0721750 * b,
+            z: 0.0193339 * r + 0.1191920 * g + 0.9503041 * b,
+        }
+    }
+}
+
+impl From<oklab> for xyz {
+    fn from(value: oklab) -> Self {
+        rgb::from(value).into()
+    }
+}
+
+impl From<okhsl> for xyz {
+    fn from(value: okhsl) -> Self {
+        rgb::from(value).into()
+    }
+}
+
+impl From<okhsv> for xyz {
+    fn from(value: okhsv) -> Self {
+        rgb::from(value).into()
+    }
+}
+
+impl From<hsl> for xyz {
+    fn from(value: hsl) -> Self {
+        rgb::from(value).into()
+    }
+}
+
+impl From<hsv> for xyz {
+    fn from(value: hsv) -> Self {
+        rgb::from(value).into()
+    }
+}
+
+impl From<cielab> for xyz {
+    fn from(value: cielab) -> Self {
+        value.to_xyz_with_white(xyz::D65)
+    }
+}
+
+impl From<cielch> for xyz {
+    fn from(value: cielch) -> Self {
+        cielab::from(value).into()
+    }
+}
+
+impl From<xyy> for xyz {
+    fn from(value: xyy) -> Self {
+        // y is 0 only for black, whatever the chromaticity
+        if value.y == 0.0 {
+            return xyz::default();
+        }
+
+        let scale = value.Y / value.y;
+        Self {
+            x: value.x * scale,
+            y: value.Y,
+            z: (1.0 - value.x - value.y) * scale,
+        }
+    }
+}
+
+impl From<hsi> for xyz {
+    fn from(value: hsi) -> Self {
+        srgb::from(value).into()
+    }
+}
+
+impl From<oklch> for xyz {
+    fn from(value: oklch) -> Self {
+        oklab::from(value).into()
+    }
+}
+
+/// a color in the cielab color space (d65). `l` ranges from 0 to 100, `a` and `b` are roughly within ±128
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct cielab {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+}
+
+impl cielab {
+    const DELTA: f32 = 6.0 / 29.0;
+
+    fn f(t: f32) -> f32 {
+        if t > Self::DELTA * Self::DELTA * Self::DELTA {
+            t.cbrt()
+        } else {
+            t / (3.0 * Self::DELTA * Self::DELTA) + 4.0 / 29.0
+        }
+    }
+
+    fn f_inv(t: f32) -> f32 {
+        if t > Self::DELTA {
+            t * t * t
+        } else {
+            3.0 * Self::DELTA * Self::DELTA * (t - 4.0 / 29.0)
+        }
+    }
+
+    fn from_xyz_with_white(xyz { x, y, z }: xyz, white: xyz) -> Self {
+        let fx = cielab::f(x / white.x);
+        let fy = cielab::f(y / white.y);
+        let fz = cielab::f(z / white.z);
+
+        Self {
+            l: 116.0 * fy - 16.0,
+            a: 500.0 * (fx - fy),
+            b: 200.0 * (fy - fz),
+        }
+    }
+
+    fn to_xyz_with_white(self, white: xyz) -> xyz {
+        let fy = (self.l + 16.0) / 116.0;
+        let fx = fy + self.a / 500.0;
+        let fz = fy - self.b / 200.0;
+
+        xyz {
+            x: white.x * cielab::f_inv(fx),
+            y: white.y * cielab::f_inv(fy),
+            z: white.z * cielab::f_inv(fz),
+        }
+    }
+
+    /// treats the color as d50 relative lab, like [xyz::to_cielab_d50] produces, and converts it back to d65 xyz
+    pub fn d50_to_xyz(self) -> xyz {
+        xyz::from_d50(self.to_xyz_with_white(xyz::D50))
+    }
+}
+impl Color for cielab {}
+
+impl From<[f32; 3]> for cielab {
+    fn from(value: [f32; 3]) -> Self {
+        Self {
+            l: value[0],
+            a: value[1],
+            b: value[2],
+        }
+    }
+}
+
+impl From<cielab> for [f32; 3] {
+    fn from(value: cielab) -> Self {
+        [value.l, value.a, value.b]
+    }
+}
+
+impl From<(f32, f32, f32)> for cielab {
+    fn from((a, b, c): (f32, f32, f32)) -> Self {
+        [a, b, c].into()
+    }
+}
+
+impl From<cielab> for (f32, f32, f32) {
+    fn from(value: cielab) -> Self {
+        let [a, b, c] = value.into();
+        (a, b, c)
+    }
+}
+
+impl TryFrom<&[f32]> for cielab {
+    type Error = ColorError;
+
+    fn try_from(value: &[f32]) -> Result<Self, ColorError> {
+        <[f32; 3]>::try_from(value)
+            .map(Self::from)
+            .map_err(|_| ColorError::WrongLength {
+                expected: 3,
+                found: value.len(),
+            })
+    }
+}
+
+impl From<srgb> for cielab {
+    fn from(value: srgb) -> Self {
+        xyz::from(value).into()
+    }
+}
+
+impl From<rgb> for cielab {
+    fn from(value: rgb) -> Self {
+        xyz::from(value).into()
+    }
+}
+
+impl From<oklab> for cielab {
+    fn from(value: oklab) -> Self {
+        xyz::from(value).into()
+    }
+}
+
+impl From<okhsl> for cielab {
+    fn from(value: okhsl) -> Self {
+        xyz::from(value).into()
+    }
+}
+
+impl From<okhsv> for cielab {
+    fn from(value: okhsv) -> Self {
+        xyz::from(value).into()
+    }
+}
+
+impl From<hsl> for cielab {
+    fn from(value: hsl) -> Self {
+        xyz::from(value).into()
+    }
+}
+
+impl From<hsv> for cielab {
+    fn from(value: hsv) -> Self {
+        xyz::from(value).into()
+    }
+}
+
+impl From<xyz> for cielab {
+    fn from(value: xyz) -> Self {
+        cielab::from_xyz_with_white(value, xyz::D65)
+    }
+}
+
+impl From<cielch> for cielab {
+    fn from(cielch { l, c, h }: cielch) -> Self {
+        let (sin, cos) = (h * std::f32::consts::TAU).sin_cos();
+
+        Self {
+            l,
+            a: c * cos,
+            b: c * sin,
+        }
+    }
+}
+
+impl From<hsi> for cielab {
+    fn from(value: hsi) -> Self {
+        srgb::from(value).into()
+    }
+}
+
+impl From<oklch> for cielab {
+    fn from(value: oklch) -> Self {
+        oklab::from(value).into()
+    }
+}
+
+impl From<xyy> for cielab {
+    fn from(value: xyy) -> Self {
+        xyz::from(value).into()
+    }
+}
+
+/// a color in the cielch color space, the polar form of [cielab]. like the other cylindrical spaces in this crate, `h` ranges from 0 to 1
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct cielch {
+    pub l: f32,
+    pub c: f32,
+    pub h: f32,
+}
+
+impl cielch {
+    /// chroma below this is treated as achromatic, and gets a hue of 0 instead of whatever atan2 makes of the noise
+    const ACHROMATIC_THRESHOLD: f32 = 1e-4;
+}
+impl Color for cielch {
+    /// like the default, but the hue takes the short way around the wheel and comes out wrapped into [0, 1)
+    fn lerp(self, other: Self, t: f32) -> Self {
+        Self {
+            l: self.l + (other.l - self.l) * t,
+            c: self.c + (other.c - self.c) * t,
+            h: hue::lerp_hue(self.h, other.h, t),
+        }
+    }
+}
+
+impl From<[f32; 3]> for cielch {
+    fn from(value: [f32; 3]) -> Self {
+        Self {
+            l: value[0],
+            c: value[1],
+            h: value[2],
+        }
+    }
+}
+
+impl From<cielch> for [f32; 3] {
+    fn from(value: cielch) -> Self {
+        [value.l, value.c, value.h]
+    }
+}
+
+impl From<(f32, f32, f32)> for cielch {
+    fn from((a, b, c): (f32, f32, f32)) -> Self {
+        [a, b, c].into()
+    }
+}
+
+impl From<cielch> for (f32, f32, f32) {
+    fn from(value: cielch) -> Self {
+        let [a, b, c] = value.into();
+        (a, b, c)
+    }
+}
+
+impl TryFrom<&[f32]> for cielch {
+    type Error = ColorError;
+
+    fn try_from(value: &[f32]) -> Result<Self, ColorError> {
+        <[f32; 3]>::try_from(value)
+            .map(Self::from)
+            .map_err(|_| ColorError::WrongLength {
+                expected: 3,
+                found: value.len(),
+            })
+    }
+}
+
+impl From<srgb> for cielch {
+    fn from(value: srgb) -> Self {
+        cielab::from(value).into()
+    }
+}
+
+impl From<rgb> for cielch {
+    fn from(value: rgb) -> Self {
+        cielab::from(value).into()
+    }
+}
+
+impl From<oklab> for cielch {
+    fn from(value: oklab) -> Self {
+        cielab::from(value).into()
+    }
+}
+
+impl From<okhsl> for cielch {
+    fn from(value: okhsl) -> Self {
+        cielab::from(value).into()
+    }
+}
+
+impl From<okhsv> for cielch {
+    fn from(value: okhsv) -> Self {
+        cielab::from(value).into()
+    }
+}
+
+impl From<hsl> for cielch {
+    fn from(value: hsl) -> Self {
+        cielab::from(value).into()
+    }
+}
+
+impl From<hsv> for cielch {
+    fn from(value: hsv) -> Self {
+        cielab::from(value).into()
+    }
+}
+
+impl From<xyz> for cielch {
+    fn from(value: xyz) -> Self {
+        cielab::from(value).into()
+    }
+}
+
+impl From<cielab> for cielch {
+    fn from(cielab { l, a, b }: cielab) -> Self {
+        let c = (a * a + b * b).sqrt();
+
+        let h = if c < cielch::ACHROMATIC_THRESHOLD {
+            0.0
+        } else {
+            (b.atan2(a) / std::f32::consts::TAU).rem_euclid(1.0)
+        };
+
+        Self { l, c, h }
+    }
+}
+
+impl From<hsi> for cielch {
+    fn from(value: hsi) -> Self {
+        srgb::from(value).into()
+    }
+}
+
+impl From<oklch> for cielch {
+    fn from(value: oklch) -> Self {
+        oklab::from(value).into()
+    }
+}
+
+impl From<xyy> for cielch {
+    fn from(value: xyy) -> Self {
+        xyz::from(value).into()
+    }
+}
+
+/// a color in the hsi color space. `i` is the plain average of the srgb channels, and `s` is measured against it rather than against the max channel like hsv and hsl
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct hsi {
+    pub h: f32,
+    pub s: f32,
+    pub i: f32,
+}
+impl Color for hsi {
+    /// like the default, but the hue takes the short way around the wheel and comes out wrapped into [0, 1)
+    fn lerp(self, other: Self, t: f32) -> Self {
+        Self {
+            h: hue::lerp_hue(self.h, other.h, t),
+            s: self.s + (other.s - self.s) * t,
+            i: self.i + (other.i - self.i) * t,
+        }
+    }
+}
+
+impl From<[f32; 3]> for hsi {
+    fn from(value: [f32; 3]) -> Self {
+        Self {
+            h: value[0],
+            s: value[1],
+            i: value[2],
+        }
+    }
+}
+
+impl From<hsi> for [f32; 3] {
+    fn from(value: hsi) -> Self {
+        [value.h, value.s, value.i]
+    }
+}
+
+impl From<(f32, f32, f32)> for hsi {
+    fn from((a, b, c): (f32, f32, f32)) -> Self {
+        [a, b, c].into()
+    }
+}
+
+impl From<hsi> for (f32, f32, f32) {
+    fn from(value: hsi) -> Self {
+        let [a, b, c] = value.into();
+        (a, b, c)
+    }
+}
+
+impl TryFrom<&[f32]> for hsi {
+    type Error = ColorError;
+
+    fn try_from(value: &[f32]) -> Result<Self, ColorError> {
+        <[f32; 3]>::try_from(value)
+            .map(Self::from)
+            .map_err(|_| ColorError::WrongLength {
+                expected: 3,
+                found: value.len(),
+            })
+    }
+}
+
+impl From<srgb> for hsi {
+    fn from(srgb { r, g, b }: srgb) -> Self {
+        let i = (r + g + b) / 3.0;
+        let min = r.min(g.min(b));
+
+        let s = if i == 0.0 { 0.0 } else { 1.0 - min / i };
+
+        let num = 0.5 * ((r - g) + (r - b));
+        let den = ((r - g) * (r - g) + (r - b) * (g - b)).sqrt();
+
+        // the denominator only vanishes when r == g == b, where hue is undefined
+        let h = if den == 0.0 {
+            0.0
+        } else {
+            let theta = (num / den).clamp(-1.0, 1.0).acos() / std::f32::consts::TAU;
+            if b > g { 1.0 - theta } else { theta }
+        };
+
+        Self { h, s, i }
+    }
+}
+
+impl From<rgb> for hsi {
+    fn from(value: rgb) -> Self {
+        srgb::from(value).into()
+    }
+}
+
+impl From<oklab> for hsi {
+    fn from(value: oklab) -> Self {
+        srgb::from(value).into()
+    }
+}
+
+impl From<okhsl> for hsi {
+    fn from(value: okhsl) -> Self {
+        srgb::from(value).into()
+    }
+}
+
+impl From<okhsv> for hsi {
+    fn from(value: okhsv) -> Self {
+        srgb::from(value).into()
+    }
+}
+
+impl From<hsl> for hsi {
+    fn from(value: hsl) -> Self {
+        srgb::from(value).into()
+    }
+}
+
+impl From<hsv> for hsi {
+    fn from(value: hsv) -> Self {
+        srgb::from(value).into()
+    }
+}
+
+impl From<xyz> for hsi {
+    fn from(value: xyz) -> Self {
+        srgb::from(value).into()
+    }
+}
+
+impl From<cielab> for hsi {
+    fn from(value: cielab) -> Self {
+        srgb::from(value).into()
+    }
+}
+
+impl From<cielch> for hsi {
+    fn from(value: cielch) -> Self {
+        srgb::from(value).into()
+    }
+}
+
+impl From<oklch> for hsi {
+    fn from(value: oklch) -> Self {
+        srgb::from(value).into()
+    }
+}
+
+impl From<xyy> for hsi {
+    fn from(value: xyy) -> Self {
+        xyz::from(value).into()
+    }
+}
+
+/// the luma coefficients used by a [ycbcr] encoding
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum YCbCrMatrix {
+    /// standard definition video, and jpeg
+    Bt601,
+    /// high definition video
+    #[default]
+    Bt709,
+}
+
+impl YCbCrMatrix {
+    /// returns the red and blue luma coefficients, kr and kb
+    fn coefficients(self) -> (f32, f32) {
+        match self {
+            YCbCrMatrix::Bt601 => (0.299, 0.114),
+            YCbCrMatrix::Bt709 => (0.2126, 0.0722),
+        }
+    }
+}
+
+/// the quantization range used by a [ycbcr] encoding
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum YCbCrRange {
+    /// luma and chroma use all 256 code values
+    Full,
+    /// luma is in 16–235 and chroma is in 16–240, as used by most broadcast video
+    #[default]
+    Limited,
+}
+
+/// a color in the ycbcr color space. the channels are 8-bit code values divided by 255, so the quantization [range](YCbCrRange) is baked into them
+///
+/// ycbcr is an encoding of srgb rather than a color space of its own, so converting needs to know the [matrix](YCbCrMatrix) and range and there are no From impls
+///
+/// ```
+/// use tinycolors::{srgb, ycbcr, YCbCrMatrix, YCbCrRange};
+///
+/// let white = ycbcr::from_srgb(srgb::WHITE, YCbCrMatrix::Bt709, YCbCrRange::Limited);
+/// assert_eq!(white.y, 235.0 / 255.0);
+/// ```
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ycbcr {
+    pub y: f32,
+    pub cb: f32,
+    pub cr: f32,
+}
+
+impl ycbcr {
+    /// encodes a gamma encoded srgb color with the luma coefficients of `matrix`. for an in gamut color, full range puts y in [0, 1] and cb and cr within 0.5 of 128/255, and limited range puts y in [16/255, 235/255] and cb and cr in [16/255, 240/255]. channels outside of [0, 1] aren't clamped, so they encode to values outside of those ranges
+    pub fn from_srgb(color: srgb, matrix: YCbCrMatrix, range: YCbCrRange) -> Self {
+        let (kr, kb) = matrix.coefficients();
+        let kg = 1.0 - kr - kb;
+
+        let y = kr * color.r + kg * color.g + kb * color.b;
+        let cb = (color.b - y) / (2.0 * (1.0 - kb));
+        let cr = (color.r - y) / (2.0 * (1.0 - kr));
+
+        match range {
+            YCbCrRange::Full => Self {
+                y,
+                cb: cb + 128.0 / 255.0,
+                cr: cr + 128.0 / 255.0,
+            },
+            YCbCrRange::Limited => Self {
+                y: (16.0 + 219.0 * y) / 255.0,
+                cb: (128.0 + 224.0 * cb) / 255.0,
+                cr: (128.0 + 224.0 * cr) / 255.0,
+            },
+        }
+    }
+
+    /// decodes to gamma encoded srgb, the inverse of [ycbcr::from_srgb]. `matrix` and `range` have to be the ones the color was encoded with, and code values outside of the range decode to channels outside of [0, 1] rather than being clamped
+    pub fn to_srgb(self, matrix: YCbCrMatrix, range: YCbCrRange) -> srgb {
+        let (kr, kb) = matrix.coefficients();
+        let kg = 1.0 - kr - kb;
+
+        let (y, cb, cr) = match range {
+            YCbCrRange::Full => (self.y, self.cb - 128.0 / 255.0, self.cr - 128.0 / 255.0),
+            YCbCrRange::Limited => (
+                (self.y * 255.0 - 16.0) / 219.0,
+                (self.cb * 255.0 - 128.0) / 224.0,
+                (self.cr * 255.0 - 128.0) / 224.0,
+            ),
+        };
+
+        let r = y + 2.0 * (1.0 - kr) * cr;
+        let b = y + 2.0 * (1.0 - kb) * cb;
+        let g = (y - kr * r - kb * b) / kg;
+
+        srgb { r, g, b }
+    }
+
+    /// [ycbcr::from_srgb] for linear rgb. the matrix applies to the gamma encoded values, like it does in video, so the color is encoded to srgb first
+    pub fn from_rgb(color: rgb, matrix: YCbCrMatrix, range: YCbCrRange) -> Self {
+        Self::from_srgb(color.into(), matrix, range)
+    }
+
+    /// [ycbcr::to_srgb], then linearized to rgb
+    pub fn to_rgb(self, matrix: YCbCrMatrix, range: YCbCrRange) -> rgb {
+        self.to_srgb(matrix, range).into()
+    }
+}
+
+impl From<[f32; 3]> for ycbcr {
+    fn from(value: [f32; 3]) -> Self {
+        Self {
+            y: value[0],
+            cb: value[1],
+            cr: value[2],
+        }
+    }
+}
+
+impl From<ycbcr> for [f32; 3] {
+    fn from(value: ycbcr) -> Self {
+        [value.y, value.cb, value.cr]
+    }
+}
+
+impl From<(f32, f32, f32)> for ycbcr {
+    fn from((a, b, c): (f32, f32, f32)) -> Self {
+        [a, b, c].into()
+    }
+}
+
+impl From<ycbcr> for (f32, f32, f32) {
+    fn from(value: ycbcr) -> Self {
+        let [a, b, c] = value.into();
+        (a, b, c)
+    }
+}
+
+impl TryFrom<&[f32]> for ycbcr {
+    type Error = ColorError;
+
+    fn try_from(value: &[f32]) -> Result<Self, ColorError> {
+        <[f32; 3]>::try_from(value)
+            .map(Self::from)
+            .map_err(|_| ColorError::WrongLength {
+                expected: 3,
+                found: value.len(),
+            })
+    }
+}
+
+/// a color in the oklch color space, the polar form of [oklab]. like the other cylindrical spaces in this crate, `h` ranges from 0 to 1
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct oklch {
+    pub l: f32,
+    pub c: f32,
+    pub h: f32,
+}
+
+impl oklch {
+    /// chroma below this is treated as achromatic, and gets a hue of 0 instead of whatever atan2 makes of the noise
+    const ACHROMATIC_THRESHOLD: f32 = 1e-6;
+}
+impl Color for oklch {
+    /// like the default, but the hue takes the short way around the wheel and comes out wrapped into [0, 1)
+    fn lerp(self, other: Self, t: f32) -> Self {
+        Self {
+            l: self.l + (other.l - self.l) * t,
+            c: self.c + (other.c - self.c) * t,
+            h: hue::lerp_hue(self.h, other.h, t),
+        }
+    }
+}
+
+impl From<[f32; 3]> for oklch {
+    fn from(value: [f32; 3]) -> Self {
+        Self {
+            l: value[0],
+            c: value[1],
+            h: value[2],
+        }
+    }
+}
+
+impl From<oklch> for [f32; 3] {
+    fn from(value: oklch) -> Self {
+        [value.l, value.c, value.h]
+    }
+}
+
+impl From<(f32, f32, f32)> for oklch {
+    fn from((a, b, c): (f32, f32, f32)) -> Self {
+        [a, b, c].into()
+    }
+}
+
+impl From<oklch> for (f32, f32, f32) {
+    fn from(value: oklch) -> Self {
+        let [a, b, c] = value.into();
+        (a, b, c)
+    }
+}
+
+impl TryFrom<&[f32]> for oklch {
+    type Error = ColorError;
+
+    fn try_from(value: &[f32]) -> Result<Self, ColorError> {
+        <[f32; 3]>::try_from(value)
+            .map(Self::from)
+            .map_err(|_| ColorError::WrongLength {
+                expected: 3,
+                found: value.len(),
+            })
+    }
+}
+
+impl From<srgb> for oklch {
+    fn from(value: srgb) -> Self {
+        oklab::from(value).into()
+    }
+}
+
+impl From<rgb> for oklch {
+    fn from(value: rgb) -> Self {
+        oklab::from(value).into()
+    }
+}
+
+impl From<oklab> for oklch {
+    fn from(oklab { l, a, b }: oklab) -> Self {
+        let c = (a * a + b * b).sqrt();
+
+        let h = if c < oklch::ACHROMATIC_THRESHOLD {
+            0.0
+        } else {
+            (b.atan2(a) / std::f32::consts::TAU).rem_euclid(1.0)
+        };
+
+        Self { l, c, h }
+    }
+}
+
+impl From<okhsl> for oklch {
+    fn from(value: okhsl) -> Self {
+        oklab::from(value).into()
+    }
+}
+
+impl From<okhsv> for oklch {
+    fn from(value: okhsv) -> Self {
+        oklab::from(value).into()
+    }
+}
+
+impl From<hsl> for oklch {
+    fn from(value: hsl) -> Self {
+        oklab::from(value).into()
+    }
+}
+
+impl From<hsv> for oklch {
+    fn from(value: hsv) -> Self {
+        oklab::from(value).into()
+    }
+}
+
+impl From<xyz> for oklch {
+    fn from(value: xyz) -> Self {
+        oklab::from(value).into()
+    }
+}
+
+impl From<cielab> for oklch {
+    fn from(value: cielab) -> Self {
+        oklab::from(value).into()
+    }
+}
+
+impl From<cielch> for oklch {
+    fn from(value: cielch) -> Self {
+        oklab::from(value).into()
+    }
+}
+
+impl From<hsi> for oklch {
+    fn from(value: hsi) -> Self {
+        srgb::from(value).into()
+    }
+}
+
+impl From<xyy> for oklch {
+    fn from(value: xyy) -> Self {
+        xyz::from(value).into()
+    }
+}
+
+/// a color in the cie xyy color space, the chromaticity coordinates `x` and `y` of [xyz] with its luminance `Y` carried over unchanged. black has no chromaticity, so by convention it gets the chromaticity of the d65 white point
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct xyy {
+    pub x: f32,
+    pub y: f32,
+    pub Y: f32,
+}
+
+impl xyy {
+    /// the chromaticity of the d65 white point, which black is given
+    pub const D65_CHROMATICITY: (f32, f32) = (0.3127, 0.3290);
+}
+impl Color for xyy {}
+
+impl From<[f32; 3]> for xyy {
+    fn from(value: [f32; 3]) -> Self {
+        Self {
+            x: value[0],
+            y: value[1],
+            Y: value[2],
+        }
+    }
+}
+
+impl From<xyy> for [f32; 3] {
+    fn from(value: xyy) -> Self {
+        [value.x, value.y, value.Y]
+    }
+}
+
+impl From<(f32, f32, f32)> for xyy {
+    fn from((a, b, c): (f32, f32, f32)) -> Self {
+        [a, b, c].into()
+    }
+}
+
+impl From<xyy> for (f32, f32, f32) {
+    fn from(value: xyy) -> Self {
+        let [a, b, c] = value.into();
+        (a, b, c)
+    }
+}
+
+impl TryFrom<&[f32]> for xyy {
+    type Error = ColorError;
+
+    fn try_from(value: &[f32]) -> Result<Self, ColorError> {
+        <[f32; 3]>::try_from(value)
+            .map(Self::from)
+            .map_err(|_| ColorError::WrongLength {
+                expected: 3,
+                found: value.len(),
+            })
+    }
+}
+
+impl From<srgb> for xyy {
+    fn from(value: srgb) -> Self {
+        xyz::from(value).into()
+    }
+}
+
+impl From<rgb> for xyy {
+    fn from(value: rgb) -> Self {
+        xyz::from(value).into()
+    }
+}
+
+impl From<oklab> for xyy {
+    fn from(value: oklab) -> Self {
+        xyz::from(value).into()
+    }
+}
+
+impl From<okhsl> for xyy {
+    fn from(value: okhsl) -> Self {
+        xyz::from(value).into()
+    }
+}
+
+impl From<okhsv> for xyy {
+    fn from(value: okhsv) -> Self {
+        xyz::from(value).into()
+    }
+}
+
+impl From<hsl> for xyy {
+    fn from(value: hsl) -> Self {
+        xyz::from(value).into()
+    }
+}
+
+impl From<hsv> for xyy {
+    fn from(value: hsv) -> Self {
+        xyz::from(value).into()
+    }
+}
+
+impl From<xyz> for xyy {
+    fn from(xyz { x, y, z }: xyz) -> Self {
+        let sum = x + y + z;
+        if sum == 0.0 {
+            let (x, y) = xyy::D65_CHROMATICITY;
+            return Self { x, y, Y: 0.0 };
+        }
+
+        Self {
+            x: x / sum,
+            y: y / sum,
+            Y: y,
+        }
+    }
+}
+
+impl From<cielab> for xyy {
+    fn from(value: cielab) -> Self {
+        xyz::from(value).into()
+    }
+}
+
+impl From<cielch> for xyy {
+    fn from(value: cielch) -> Self {
+        xyz::from(value).into()
+    }
+}
+
+impl From<hsi> for xyy {
+    fn from(value: hsi) -> Self {
+        xyz::from(value).into()
+    }
+}
+
+impl From<oklch> for xyy {
+    fn from(value: oklch) -> Self {
+        xyz::from(value).into()
+    }
+}
+
+/// a color in the srgb color space, with a straight (not premultiplied) alpha channel
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct srgba {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+impl srgba {
+    pub const TRANSPARENT: srgba = srgba {
+        r: 0.0,
+        g: 0.0,
+        b: 0.0,
+        a: 0.0,
+    };
+
+    /// the color without its alpha
+    pub fn rgb(&self) -> srgb {
+        srgb {
+            r: self.r,
+            g: self.g,
+            b: self.b,
+        }
+    }
+
+    /// pairs a color with an alpha
+    pub fn with_alpha(color: srgb, a: f32) -> Self {
+        let srgb { r, g, b } = color;
+        Self { r, g, b, a }
+    }
+
+    /// builds a color from linear rgb premultiplied by alpha, the layout gpus blend in. a zero alpha gives [srgba::TRANSPARENT]
+    pub fn from_premultiplied_array([r, g, b, a]: [f32; 4]) -> Self {
+        if a == 0.0 {
+            return Self::TRANSPARENT;
+        }
+
+        Self::with_alpha(
+            rgb {
+                r: r / a,
+                g: g / a,
+                b: b / a,
+            }
+            .into(),
+            a,
+        )
+    }
+
+    /// converts the color to linear rgb premultiplied by alpha, the inverse of [srgba::from_premultiplied_array]
+    pub fn to_premultiplied_array(&self) -> [f32; 4] {
+        let rgb { r, g, b } = self.rgb().into();
+        [r * self.a, g * self.a, b * self.a, self.a]
+    }
+}
+
+/// opaque black, to match the defaults of the other structs
+impl Default for srgba {
+    fn default() -> Self {
+        Self::with_alpha(srgb::default(), 1.0)
+    }
+}
+
+impl From<[f32; 4]> for srgba {
+    fn from(value: [f32; 4]) -> Self {
+        Self {
+            r: value[0],
+            g: value[1],
+            b: value[2],
+            a: value[3],
+        }
+    }
+}
+
+impl From<srgba> for [f32; 4] {
+    fn from(value: srgba) -> Self {
+        [value.r, value.g, value.b, value.a]
+    }
+}
+
+impl From<(f32, f32, f32, f32)> for srgba {
+    fn from((r, g, b, a): (f32, f32, f32, f32)) -> Self {
+        [r, g, b, a].into()
+    }
+}
+
+impl From<srgba> for (f32, f32, f32, f32) {
+    fn from(value: srgba) -> Self {
+        let [r, g, b, a] = value.into();
+        (r, g, b, a)
+    }
+}
+
+impl TryFrom<&[f32]> for srgba {
+    type Error = ColorError;
+
+    fn try_from(value: &[f32]) -> Result<Self, ColorError> {
+        <[f32; 4]>::try_from(value)
+            .map(Self::from)
+            .map_err(|_| ColorError::WrongLength {
+                expected: 4,
+                found: value.len(),
+            })
+    }
+}
+
+/// the color becomes fully opaque
+impl From<srgb> for srgba {
+    fn from(value: srgb) -> Self {
+        Self::with_alpha(value, 1.0)
+    }
+}
+
+/// the alpha is dropped
+impl From<srgba> for srgb {
+    fn from(value: srgba) -> Self {
+        value.rgb()
+    }
+}
+
+/// a color in the cmyk color model, each ink from 0 to 1. the conversions are the naive device formulas with no ink limits or icc profile, so they only approximate what a press would print
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct cmyk {
+    pub c: f32,
+    pub m: f32,
+    pub y: f32,
+    pub k: f32,
+}
+
+impl From<[f32; 4]> for cmyk {
+    fn from(value: [f32; 4]) -> Self {
+        Self {
+            c: value[0],
+            m: value[1],
+            y: value[2],
+            k: value[3],
+        }
+    }
+}
+
+impl From<cmyk> for [f32; 4] {
+    fn from(value: cmyk) -> Self {
+        [value.c, value.m, value.y, value.k]
+    }
+}
+
+impl From<(f32, f32, f32, f32)> for cmyk {
+    fn from((c, m, y, k): (f32, f32, f32, f32)) -> Self {
+        [c, m, y, k].into()
+    }
+}
+
+impl From<cmyk> for (f32, f32, f32, f32) {
+    fn from(value: cmyk) -> Self {
+        let [c, m, y, k] = value.into();
+        (c, m, y, k)
+    }
+}
+
+impl TryFrom<&[f32]> for cmyk {
+    type Error = ColorError;
+
+    fn try_from(value: &[f32]) -> Result<Self, ColorError> {
+        <[f32; 4]>::try_from(value)
+            .map(Self::from)
+            .map_err(|_| ColorError::WrongLength {
+                expected: 4,
+                found: value.len(),
+            })
+    }
+}
+
+/// black takes as much of the color as it can, and the other inks make up the rest. channels are clamped to [0, 1] first
+impl From<srgb> for cmyk {
+    fn from(value: srgb) -> Self {
+        let [r, g, b] = [value.r, value.g, value.b].map(|c| c.clamp(0.0, 1.0));
+        let k = 1.0 - r.max(g).max(b);
+        if k >= 1.0 {
+            return Self {
+                k: 1.0,
+                ..Self::default()
+            };
+        }
+
+        Self {
+            c: (1.0 - r - k) / (1.0 - k),
+            m: (1.0 - g - k) / (1.0 - k),
+            y: (1.0 - b - k) / (1.0 - k),
+            k,
+        }
+    }
+}
+
+impl From<cmyk> for srgb {
+    fn from(cmyk { c, m, y, k }: cmyk) -> Self {
+        Self {
+            r: (1.0 - c) * (1.0 - k),
+            g: (1.0 - m) * (1.0 - k),
+            b: (1.0 - y) * (1.0 - k),
+        }
+    }
+}
+
+impl srgb {
+    /// the total ink the color takes in the naive [cmyk] conversion, c + m + y + k, where 1.0 is 100% coverage. presses usually cap it somewhere around 2.8 to 3.2. white needs no ink, black needs 1.0, and saturated darks come closest to the naive conversion's limit of 3.0
+    pub fn ink_coverage(&self) -> f32 {
+        let cmyk { c, m, y, k } = (*self).into();
+        c + m + y + k
+    }
+}
@@ -0,0 +1,100 @@
+use crate::{rgb, srgb, xyy};
+
+impl srgb {
+    /// approximates the color of monochromatic light with a wavelength of `nm` nanometers, using dan bruton's piecewise fit of the visible spectrum. intensity falls off towards both ends, and wavelengths outside of 380–780nm are black
+    pub fn from_wavelength(nm: f32) -> srgb {
+        if !(380.0..=780.0).contains(&nm) {
+            return srgb::BLACK;
+        }
+
+        let (r, g, b) = if nm < 440.0 {
+            ((440.0 - nm) / (440.0 - 380.0), 0.0, 1.0)
+        } else if nm < 490.0 {
+            (0.0, (nm - 440.0) / (490.0 - 440.0), 1.0)
+        } else if nm < 510.0 {
+            (0.0, 1.0, (510.0 - nm) / (510.0 - 490.0))
+        } else if nm < 580.0 {
+            ((nm - 510.0) / (580.0 - 510.0), 1.0, 0.0)
+        } else if nm < 645.0 {
+            (1.0, (645.0 - nm) / (645.0 - 580.0), 0.0)
+        } else {
+            (1.0, 0.0, 0.0)
+        };
+
+        // the eye is less sensitive near the edges of the visible range
+        let intensity = if nm < 420.0 {
+            0.3 + 0.7 * (nm - 380.0) / (420.0 - 380.0)
+        } else if nm <= 700.0 {
+            1.0
+        } else {
+            0.3 + 0.7 * (780.0 - nm) / (780.0 - 700.0)
+        };
+
+        const GAMMA: f32 = 0.8;
+
+        srgb {
+            r: f32::powf(r * intensity, GAMMA),
+            g: f32::powf(g * intensity, GAMMA),
+            b: f32::powf(b * intensity, GAMMA),
+        }
+    }
+
+    /// the color of a black body at `kelvin`, from the planckian locus. the result is normalized so the brightest channel is 1.0. temperatures are clamped to 1667–25000k, the range the locus fit covers
+    pub fn from_kelvin(kelvin: f32) -> srgb {
+        from_uv(planckian_uv(kelvin))
+    }
+
+    /// [srgb::from_kelvin] with a tint along the green–magenta axis, like the two white balance sliders in raw editors. `tint` is the distance from the planckian locus in cie 1960 uv (duv), positive towards green and negative towards magenta. ±0.02 is already a strong cast
+    pub fn from_temp_tint(kelvin: f32, tint: f32) -> srgb {
+        let (u, v) = planckian_uv(kelvin);
+
+        // the locus runs along the temperature axis, so tint moves perpendicular to it
+        let (u1, v1) = planckian_uv(kelvin * 1.01);
+        let (du, dv) = (u1 - u, v1 - v);
+        let length = (du * du + dv * dv).sqrt();
+        let (nu, nv) = if du > 0.0 {
+            (-dv / length, du / length)
+        } else {
+            (dv / length, -du / length)
+        };
+
+        from_uv((u + nu * tint, v + nv * tint))
+    }
+}
+
+/// the cie 1960 uv chromaticity of a black body at `kelvin`, using kim et al.'s cubic fit of the planckian locus
+fn planckian_uv(kelvin: f32) -> (f32, f32) {
+    let t = kelvin.clamp(1667.0, 25000.0) as f64;
+    let (t2, t3) = (t * t, t * t * t);
+
+    let x = if t <= 4000.0 {
+        -0.2661239e9 / t3 - 0.2343589e6 / t2 + 0.8776956e3 / t + 0.179910
+    } else {
+        -3.0258469e9 / t3 + 2.1070379e6 / t2 + 0.2226347e3 / t + 0.240390
+    };
+
+    let (x2, x3) = (x * x, x * x * x);
+    let y = if t <= 2222.0 {
+        -1.1063814 * x3 - 1.34811020 * x2 + 2.18555832 * x - 0.20219683
+    } else if t <= 4000.0 {
+        -0.9549476 * x3 - 1.37418593 * x2 + 2.09137015 * x - 0.16748867
+    } else {
+        3.0817580 * x3 - 5.87338670 * x2 + 3.75112997 * x - 0.37001483
+    };
+
+    let d = -2.0 * x + 12.0 * y + 3.0;
+    ((4.0 * x / d) as f32, (6.0 * y / d) as f32)
+}
+
+/// the color at a cie 1960 uv chromaticity, scaled so its brightest channel is 1.0. channels that fall below 0 are clipped
+fn from_uv((u, v): (f32, f32)) -> srgb {
+    let d = 2.0 * u - 8.0 * v + 4.0;
+    let linear = rgb::from(xyy {
+        x: 3.0 * u / d,
+        y: 2.0 * v / d,
+        Y: 1.0,
+    });
+
+    let max = linear.r.max(linear.g).max(linear.b);
+    rgb::from(<[f32; 3]>::from(linear).map(|c| (c / max).max(0.0))).into()
+}
@@ -0,0 +1,136 @@
+use std::cmp::Ordering;
+
+use crate::{Color, oklch, rgb, srgb};
+
+/// the oklch chroma below which [sort_perceptual] groups a color with the grays
+const GRAY_CHROMA: f32 = 0.02;
+
+/// the number of bisection steps [accessible_pair] takes to find the lightness that just passes
+const CONTRAST_STEPS: usize = 24;
+
+impl srgb {
+    /// the relative luminance of the color as defined by wcag, 0.0 for black and 1.0 for white
+    pub fn relative_luminance(&self) -> f32 {
+        let rgb { r, g, b } = (*self).into();
+
+        0.2126 * r + 0.7152 * g + 0.0722 * b
+    }
+
+    /// the wcag contrast ratio between the two colors, from 1.0 for identical luminance up to 21.0 for black on white. the order of the colors doesn't matter
+    pub fn contrast_ratio(&self, other: &srgb) -> f32 {
+        let (a, b) = (self.relative_luminance(), other.relative_luminance());
+        let (lighter, darker) = if a > b { (a, b) } else { (b, a) };
+
+        (lighter + 0.05) / (darker + 0.05)
+    }
+
+    /// returns true if the relative luminance is below 0.179, the point where white text starts to contrast better than black text
+    pub fn is_dark(&self) -> bool {
+        self.relative_luminance() < 0.179
+    }
+
+    /// returns black or white, whichever has the higher contrast ratio against this color
+    pub fn best_text_color(&self) -> srgb {
+        if self.contrast_ratio(&srgb::WHITE) > self.contrast_ratio(&srgb::BLACK) {
+            srgb::WHITE
+        } else {
+            srgb::BLACK
+        }
+    }
+
+    /// the linear luminance of the color, the same value as [srgb::relative_luminance]. pairs with [srgb::from_luma] for storing single channel intensity
+    pub fn luma(&self) -> f32 {
+        self.relative_luminance()
+    }
+
+    /// the gray whose linear luminance is `y`. the inverse of [srgb::luma] for grays
+    pub fn from_luma(y: f32) -> srgb {
+        rgb { r: y, g: y, b: y }.into()
+    }
+
+    /// the gray with the same luminance as this color, so it reads equally bright
+    pub fn to_grayscale(&self) -> srgb {
+        srgb::from_luma(self.luma())
+    }
+
+    /// orders colors from dark to light by [relative luminance](srgb::relative_luminance)
+    pub fn cmp_luminance(&self, other: &Self) -> Ordering {
+        self.relative_luminance()
+            .total_cmp(&other.relative_luminance())
+    }
+}
+
+/// sorts the colors from dark to light, see [srgb::cmp_luminance]
+pub fn sort_by_luminance(colors: &mut [srgb]) {
+    colors.sort_by(srgb::cmp_luminance);
+}
+
+/// orders the colors into a calm sequence: grays first from dark to light, then everything else by oklch hue, with lightness breaking ties
+pub fn sort_perceptual(colors: &mut [srgb]) {
+    let key = |color: &srgb| {
+        let oklch { l, h, .. } = oklch::from(*color);
+        let chromatic = !color.is_gray(GRAY_CHROMA);
+        (chromatic, if chromatic { h } else { 0.0 }, l)
+    };
+
+    colors.sort_by(|a, b| {
+        let ((a_chromatic, a_h, a_l), (b_chromatic, b_h, b_l)) = (key(a), key(b));
+        a_chromatic
+            .cmp(&b_chromatic)
+            .then(a_h.total_cmp(&b_h))
+            .then(a_l.total_cmp(&b_l))
+    });
+}
+
+/// returns the candidate with the highest wcag contrast ratio against `background`, or None if there are no candidates. like [srgb::best_text_color], but for any set of colors
+pub fn most_readable(background: srgb, candidates: &[srgb]) -> Option<srgb> {
+    candidates.iter().copied().reduce(|best, candidate| {
+        if candidate.contrast_ratio(&background) > best.contrast_ratio(&background) {
+            candidate
+        } else {
+            best
+        }
+    })
+}
+
+/// returns `seed` with a partner that has at least a `min_contrast` wcag ratio against it, for building foreground and background pairs from a brand color. the partner keeps the seed's oklch hue and chroma, with its lightness moved towards black or white, whichever needs the smaller change, just far enough to pass. 4.5 is the wcag aa ratio for body text
+///
+/// if neither direction can reach `min_contrast`, the partner is black or white, whichever contrasts more
+pub fn accessible_pair(seed: srgb, min_contrast: f32) -> (srgb, srgb) {
+    let start = oklch::from(seed);
+    let at = |l: f32| oklch { l, ..start }.gamut_map();
+
+    // the lightness change needed in the direction of `end`, if it gets there at all
+    let needed = |end: f32| {
+        if at(end).contrast_ratio(&seed) < min_contrast {
+            return None;
+        }
+
+        let (mut low, mut high) = (0.0, 1.0);
+        for _ in 0..CONTRAST_STEPS {
+            let mid = (low + high) / 2.0;
+            if at(start.l + (end - start.l) * mid).contrast_ratio(&seed) >= min_contrast {
+                high = mid;
+            } else {
+                low = mid;
+            }
+        }
+
+        let l = start.l + (end - start.l) * high;
+        Some(((l - start.l).abs(), at(l)))
+    };
+
+    let partner = match (needed(0.0), needed(1.0)) {
+        (Some(darker), Some(lighter)) => {
+            if darker.0 <= lighter.0 {
+                darker.1
+            } else {
+                lighter.1
+            }
+        }
+        (Some((_, partner)), None) | (None, Some((_, partner))) => partner,
+        (None, None) => seed.best_text_color(),
+    };
+
+    (seed, partner)
+}
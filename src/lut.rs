@@ -0,0 +1,120 @@
+use crate::srgb;
+
+/// a conversion sampled on an n×n×n grid over the srgb cube, and looked up with trilinear interpolation
+///
+/// lookups cost the same no matter how expensive the conversion was, so it only pays off for slow pipelines applied to a lot of pixels, like a chain of conversions and adjustments run over every frame of a video. for a single conversion, calling it directly is about as fast and exact
+///
+/// the error shrinks with the grid size, as does the memory, which is `size³ × 12` bytes. 33 is the usual choice, and keeps smooth conversions like srgb to oklab to within about 1e-3 at 431KB. conversions with sharp corners, like clipping, need more
+///
+/// ```
+/// use tinycolors::{Lut3D, oklab, srgb};
+///
+/// let lut = Lut3D::new(33, |c: srgb| oklab::from(c).into());
+/// let lab: oklab = lut.apply(srgb::RED);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Lut3D {
+    size: usize,
+    /// indexed with red changing fastest, then green, then blue
+    table: Vec<[f32; 3]>,
+    /// the input range the grid covers per channel, [0, 1] unless loaded from a file that says otherwise
+    domain: ([f32; 3], [f32; 3]),
+}
+
+impl Lut3D {
+    /// samples `f` on a `size`×`size`×`size` grid
+    ///
+    /// # Panics
+    ///
+    /// panics if `size` is less than 2
+    pub fn new(size: usize, f: impl Fn(srgb) -> [f32; 3]) -> Self {
+        assert!(size >= 2, "a lut needs at least 2 samples per axis");
+
+        let scale = (size - 1) as f32;
+        let mut table = Vec::with_capacity(size * size * size);
+        for b in 0..size {
+            for g in 0..size {
+                for r in 0..size {
+                    table.push(f(srgb {
+                        r: r as f32 / scale,
+                        g: g as f32 / scale,
+                        b: b as f32 / scale,
+                    }));
+                }
+            }
+        }
+
+        Self {
+            size,
+            table,
+            domain: ([0.0; 3], [1.0; 3]),
+        }
+    }
+
+    /// a lut from already sampled values, laid out like [Lut3D::new] samples them, over an input `domain` of `(min, max)` per channel. the caller checks that there are `size³` values and `size` is at least 2
+    pub(crate) fn from_table(
+        size: usize,
+        table: Vec<[f32; 3]>,
+        domain: ([f32; 3], [f32; 3]),
+    ) -> Self {
+        debug_assert!(size >= 2 && table.len() == size * size * size);
+        Self {
+            size,
+            table,
+            domain,
+        }
+    }
+
+    /// the number of samples along each axis
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// looks up `color`, interpolating between the 8 surrounding samples. channels outside of [0, 1], or of the domain a loaded lut declares, are clamped
+    pub fn apply<C: From<[f32; 3]>>(&self, color: srgb) -> C {
+        let scale = (self.size - 1) as f32;
+        let (min, max) = self.domain;
+
+        // the lower grid index and the fraction of the way to the next one, per axis
+        let axis = |c: f32, k: usize| {
+            let x = ((c - min[k]) / (max[k] - min[k])).clamp(0.0, 1.0) * scale;
+            let i = (x as usize).min(self.size - 2);
+            (i, x - i as f32)
+        };
+        let (r, fr) = axis(color.r, 0);
+        let (g, fg) = axis(color.g, 1);
+        let (b, fb) = axis(color.b, 2);
+
+        let at = |r: usize, g: usize, b: usize| self.table[r + self.size * (g + self.size * b)];
+        let lerp = |x: [f32; 3], y: [f32; 3], t: f32| [0, 1, 2].map(|i| x[i] + (y[i] - x[i]) * t);
+
+        let c00 = lerp(at(r, g, b), at(r + 1, g, b), fr);
+        let c10 = lerp(at(r, g + 1, b), at(r + 1, g + 1, b), fr);
+        let c01 = lerp(at(r, g, b + 1), at(r + 1, g, b + 1), fr);
+        let c11 = lerp(at(r, g + 1, b + 1), at(r + 1, g + 1, b + 1), fr);
+
+        lerp(lerp(c00, c10, fg), lerp(c01, c11, fg), fb).into()
+    }
+}
+
+impl srgb {
+    /// runs each channel through a 1d curve, like a curves tool or a tone curve loaded from a file. `lut` samples the curve evenly over [0, 1], so a channel value `c` lands at index `c × (lut.len() - 1)`, interpolating linearly between neighboring entries. channels outside of [0, 1] are clamped first
+    ///
+    /// a single entry maps every channel to it, and an empty curve leaves the color unchanged
+    pub fn apply_curve(self, lut: &[f32]) -> srgb {
+        match lut {
+            [] => self,
+            [only] => srgb::gray(*only),
+            _ => {
+                let scale = (lut.len() - 1) as f32;
+                <[f32; 3]>::from(self)
+                    .map(|c| {
+                        let x = c.clamp(0.0, 1.0) * scale;
+                        let i = (x as usize).min(lut.len() - 2);
+                        lut[i] + (lut[i + 1] - lut[i]) * (x - i as f32)
+                    })
+                    .into()
+            }
+        }
+    }
+}
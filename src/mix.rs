@@ -0,0 +1,179 @@
+use crate::{oklab, rgb, srgb, srgba};
+
+/// averages the colors in linear rgb, which keeps the brightness of the result where the eye expects it. averaging the srgb values directly comes out too dark
+///
+/// returns black for an empty slice
+pub fn average(colors: &[srgb]) -> srgb {
+    linear_average(colors.iter().map(|&color| (color, 1.0)))
+}
+
+/// like [average], but each color contributes in proportion to its weight. weights don't need to sum to 1
+///
+/// returns black for an empty slice, or if the weights sum to zero
+pub fn weighted_average(colors: &[(srgb, f32)]) -> srgb {
+    linear_average(colors.iter().copied())
+}
+
+/// mixes two colors in linear light: decode to linear rgb, interpolate, and encode back to srgb. `t` of 0 gives `a` and 1 gives `b`
+///
+/// interpolating the srgb values directly mixes gamma encoded numbers, so the middle of a gradient comes out too dark and muddy. black and white mixed halfway give about 0.735 here, against 0.5 for the naive lerp
+pub fn mix_linear(a: srgb, b: srgb, t: f32) -> srgb {
+    lerp_linear(a, b, t)
+}
+
+/// barycentric interpolation between three linear colors, like shading across a triangle or a ternary color map. the weights are divided by their sum, so they don't need to add up to 1
+///
+/// returns black if the weights sum to zero
+pub fn blend3(a: rgb, b: rgb, c: rgb, wa: f32, wb: f32, wc: f32) -> rgb {
+    let total = wa + wb + wc;
+    if total == 0.0 {
+        return rgb::default();
+    }
+
+    let (wa, wb, wc) = (wa / total, wb / total, wc / total);
+    rgb {
+        r: a.r * wa + b.r * wb + c.r * wc,
+        g: a.g * wa + b.g * wb + c.g * wc,
+        b: a.b * wa + b.b * wb + c.b * wc,
+    }
+}
+
+/// `n` colors going from `a` to `b` inclusive, evenly spaced in oklab so the steps look even. `n` of 1 gives just `a`, and 0 gives an empty Vec
+pub fn steps(a: srgb, b: srgb, n: usize) -> Vec<srgb> {
+    let (from, to) = (oklab::from(a), oklab::from(b));
+    let last = n.saturating_sub(1).max(1) as f32;
+
+    (0..n)
+        .map(|i| {
+            let t = i as f32 / last;
+            oklab {
+                l: from.l + (to.l - from.l) * t,
+                a: from.a + (to.a - from.a) * t,
+                b: from.b + (to.b - from.b) * t,
+            }
+            .into()
+        })
+        .collect()
+}
+
+/// halves an image by averaging each 2x2 block in linear rgb, so mipmaps and thumbnails keep their brightness. returns the pixels along with the new width and height
+///
+/// odd sizes round up, and the blocks along the last row or column repeat the edge pixels
+///
+/// # Panics
+///
+/// panics if `pixels` doesn't hold exactly `width * height` pixels
+pub fn downsample_2x(pixels: &[srgb], width: usize, height: usize) -> (Vec<srgb>, usize, usize) {
+    assert_eq!(
+        pixels.len(),
+        width * height,
+        "pixels doesn't match the image size"
+    );
+
+    let (new_width, new_height) = (width.div_ceil(2), height.div_ceil(2));
+    let mut out = Vec::with_capacity(new_width * new_height);
+
+    for y in 0..new_height {
+        let rows = [2 * y, (2 * y + 1).min(height - 1)];
+        for x in 0..new_width {
+            let columns = [2 * x, (2 * x + 1).min(width - 1)];
+            let block = rows.iter().flat_map(|&row| {
+                columns
+                    .iter()
+                    .map(move |&column| (pixels[row * width + column], 1.0))
+            });
+
+            out.push(linear_average(block));
+        }
+    }
+
+    (out, new_width, new_height)
+}
+
+fn linear_average(colors: impl Iterator<Item = (srgb, f32)>) -> srgb {
+    let (sum, total) = colors.fold(([0.0; 3], 0.0), |(sum, total), (color, weight)| {
+        let rgb { r, g, b } = color.into();
+        (
+            [
+                sum[0] + r * weight,
+                sum[1] + g * weight,
+                sum[2] + b * weight,
+            ],
+            total + weight,
+        )
+    });
+
+    if total == 0.0 {
+        return srgb::BLACK;
+    }
+
+    rgb {
+        r: sum[0] / total,
+        g: sum[1] / total,
+        b: sum[2] / total,
+    }
+    .into()
+}
+
+impl srgb {
+    /// mixes the color with white. an `amount` of 0 leaves the color unchanged, and 1 gives white
+    pub fn tint(self, amount: f32) -> srgb {
+        lerp_linear(self, srgb::WHITE, amount)
+    }
+
+    /// mixes the color with black. an `amount` of 0 leaves the color unchanged, and 1 gives black
+    pub fn shade(self, amount: f32) -> srgb {
+        lerp_linear(self, srgb::BLACK, amount)
+    }
+
+    /// mixes the color with middle gray (0.5 in srgb). an `amount` of 0 leaves the color unchanged, and 1 gives the gray
+    pub fn tone(self, amount: f32) -> srgb {
+        lerp_linear(
+            self,
+            srgb {
+                r: 0.5,
+                g: 0.5,
+                b: 0.5,
+            },
+            amount,
+        )
+    }
+
+    /// mixes the color with the gray of the same oklab lightness, so it fades out without getting brighter or darker. an `amount` of 0 leaves the color unchanged, and 1 removes all of its chroma
+    pub fn mix_gray(self, amount: f32) -> srgb {
+        let color = oklab::from(self);
+        let keep = 1.0 - amount;
+
+        oklab {
+            a: color.a * keep,
+            b: color.b * keep,
+            ..color
+        }
+        .into()
+    }
+}
+
+impl srgba {
+    /// interpolates towards `other`, with premultiplied alpha in linear rgb. a fully transparent end contributes no color, so fading into transparency doesn't tint the result
+    ///
+    /// when both alphas are zero the result is [srgba::TRANSPARENT]
+    pub fn mix(self, other: srgba, t: f32) -> srgba {
+        let (from, to) = (
+            self.to_premultiplied_array(),
+            other.to_premultiplied_array(),
+        );
+
+        srgba::from_premultiplied_array([0, 1, 2, 3].map(|i| from[i] + (to[i] - from[i]) * t))
+    }
+}
+
+fn lerp_linear(a: srgb, b: srgb, t: f32) -> srgb {
+    let (a, b) = (rgb::from(a), rgb::from(b));
+
+    rgb {
+        r: a.r + (b.r - a.r) * t,
+        g: a.g + (b.g - a.g) * t,
+        b: a.b + (b.b - a.b) * t,
+    }
+    .into()
+}
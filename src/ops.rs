@@ -0,0 +1,16 @@
+use std::ops::Mul;
+
+use crate::rgb;
+
+/// multiplies channel by channel, modulating one color by another the way a tint modulates a texture. it's done in linear rgb, where multiplying light is physically meaningful
+impl Mul<rgb> for rgb {
+    type Output = rgb;
+
+    fn mul(self, rhs: rgb) -> rgb {
+        rgb {
+            r: self.r * rhs.r,
+            g: self.g * rhs.g,
+            b: self.b * rhs.b,
+        }
+    }
+}
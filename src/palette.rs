@@ -0,0 +1,245 @@
+use crate::{oklab, oklch, rgb, srgb};
+
+/// the seed [extract_palette] uses, so that extracting the same image twice gives the same palette
+pub(crate) const DEFAULT_SEED: u64 = 0x5eed;
+
+/// the most rounds of k-means to run before settling for the current centers
+const MAX_ITERATIONS: usize = 64;
+
+/// finds `k` representative colors of `pixels` using k-means clustering in oklab, ordered from the largest cluster to the smallest
+///
+/// fewer than `k` colors are returned if `pixels` doesn't contain `k` distinct colors, and none if `k` is 0
+pub fn extract_palette(pixels: &[srgb], k: usize) -> Vec<srgb> {
+    extract_palette_seeded(pixels, k, DEFAULT_SEED)
+}
+
+/// like [extract_palette], but with an explicit seed for the k-means++ initialization
+pub fn extract_palette_seeded(pixels: &[srgb], k: usize, seed: u64) -> Vec<srgb> {
+    clusters(pixels, k, seed)
+        .into_iter()
+        .map(|(center, _)| center.into())
+        .collect()
+}
+
+/// the k-means cluster centers behind [extract_palette_seeded] with the number of pixels in each, from the largest cluster to the smallest
+pub(crate) fn clusters(pixels: &[srgb], k: usize, seed: u64) -> Vec<(oklab, usize)> {
+    let points: Vec<[f32; 3]> = pixels.iter().map(|&p| oklab::from(p).into()).collect();
+    let mut rng = SplitMix64(seed);
+
+    let mut centers = init_centers(&points, k, &mut rng);
+    // with no centers every pixel would be assigned to a cluster that doesn't exist
+    if centers.is_empty() {
+        return Vec::new();
+    }
+    let mut assignments = vec![0; points.len()];
+    let mut sizes = vec![0; centers.len()];
+
+    for iteration in 0..MAX_ITERATIONS {
+        let mut changed = false;
+        for (point, assignment) in points.iter().zip(assignments.iter_mut()) {
+            let nearest = nearest(&centers, point);
+            changed |= nearest != *assignment;
+            *assignment = nearest;
+        }
+
+        if iteration > 0 && !changed {
+            break;
+        }
+
+        let mut sums = vec![[0.0; 3]; centers.len()];
+        sizes.fill(0);
+        for (point, &assignment) in points.iter().zip(&assignments) {
+            for i in 0..3 {
+                sums[assignment][i] += point[i];
+            }
+            sizes[assignment] += 1;
+        }
+
+        // an empty cluster keeps its old center
+        for ((center, sum), &size) in centers.iter_mut().zip(&sums).zip(&sizes) {
+            if size > 0 {
+                *center = sum.map(|c| c / size as f32);
+            }
+        }
+    }
+
+    let mut clusters: Vec<_> = centers.into_iter().zip(sizes).collect();
+    clusters.sort_by_key(|&(_, size)| std::cmp::Reverse(size));
+
+    clusters
+        .into_iter()
+        .map(|(center, size)| (center.into(), size))
+        .collect()
+}
+
+/// stretches the oklab lightness of the colors so the darkest lands on `min` and the lightest on `max`, with everything in between moved proportionally. hue and chroma are kept, and each color is [gamut mapped](oklch::gamut_map) after
+///
+/// if every color has the same lightness they all move to the middle of the range
+pub fn normalize_lightness(colors: &[srgb], min: f32, max: f32) -> Vec<srgb> {
+    let colors: Vec<oklch> = colors.iter().map(|&c| c.into()).collect();
+    let (darkest, lightest) = colors
+        .iter()
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), c| {
+            (lo.min(c.l), hi.max(c.l))
+        });
+
+    colors
+        .into_iter()
+        .map(|color| {
+            let l = if lightest > darkest {
+                min + (color.l - darkest) / (lightest - darkest) * (max - min)
+            } else {
+                (min + max) / 2.0
+            };
+
+            oklch { l, ..color }.gamut_map()
+        })
+        .collect()
+}
+
+impl srgb {
+    /// returns the index of the perceptually closest color in `palette`, by distance in oklab, or None if the palette is empty. on a tie the earlier color wins
+    pub fn nearest_in_palette(&self, palette: &[srgb]) -> Option<usize> {
+        let point: [f32; 3] = oklab::from(*self).into();
+
+        palette
+            .iter()
+            .map(|&color| distance2(&oklab::from(color).into(), &point))
+            .enumerate()
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(i, _)| i)
+    }
+}
+
+/// maps each pixel of a `width`×`height` image to the index of a `palette` color, using floyd-steinberg error diffusion to keep gradients from banding. the error is spread in linear rgb, so the average brightness of an area is kept
+///
+/// returns an empty Vec if the palette is empty
+///
+/// # Panics
+///
+/// panics if `pixels` doesn't hold exactly `width * height` pixels
+pub fn quantize_dithered(
+    pixels: &[srgb],
+    width: usize,
+    height: usize,
+    palette: &[srgb],
+) -> Vec<usize> {
+    assert_eq!(
+        pixels.len(),
+        width * height,
+        "pixels doesn't match the image size"
+    );
+
+    if palette.is_empty() {
+        return Vec::new();
+    }
+
+    let palette_oklab: Vec<[f32; 3]> = palette.iter().map(|&c| oklab::from(c).into()).collect();
+    let palette_linear: Vec<[f32; 3]> = palette.iter().map(|&c| rgb::from(c).into()).collect();
+
+    let mut linear: Vec<[f32; 3]> = pixels.iter().map(|&c| rgb::from(c).into()).collect();
+    let mut indices = Vec::with_capacity(pixels.len());
+
+    for y in 0..height {
+        for x in 0..width {
+            // the diffused error can push a pixel out of range, which would only get worse if carried on
+            let wanted = linear[y * width + x].map(|c| c.clamp(0.0, 1.0));
+
+            let index = nearest(&palette_oklab, &oklab::from(rgb::from(wanted)).into());
+            indices.push(index);
+
+            let chosen = palette_linear[index];
+            let error = [0, 1, 2].map(|i| wanted[i] - chosen[i]);
+
+            let mut spread = |dx: isize, dy: usize, weight: f32| {
+                let nx = x as isize + dx;
+                if nx >= 0 && (nx as usize) < width && y + dy < height {
+                    let pixel = &mut linear[(y + dy) * width + nx as usize];
+                    for i in 0..3 {
+                        pixel[i] += error[i] * weight;
+                    }
+                }
+            };
+
+            spread(1, 0, 7.0 / 16.0);
+            spread(-1, 1, 3.0 / 16.0);
+            spread(0, 1, 5.0 / 16.0);
+            spread(1, 1, 1.0 / 16.0);
+        }
+    }
+
+    indices
+}
+
+/// k-means++: each new center is picked with probability proportional to its squared distance from the closest existing one
+fn init_centers(points: &[[f32; 3]], k: usize, rng: &mut SplitMix64) -> Vec<[f32; 3]> {
+    let mut centers = Vec::with_capacity(k);
+    if points.is_empty() || k == 0 {
+        return centers;
+    }
+
+    centers.push(points[rng.below(points.len())]);
+
+    let mut distances: Vec<f32> = points.iter().map(|p| distance2(p, &centers[0])).collect();
+
+    while centers.len() < k {
+        let total: f32 = distances.iter().sum();
+        if total == 0.0 {
+            // every point already sits on a center
+            break;
+        }
+
+        let mut target = rng.next_f32() * total;
+        let mut chosen = points.len() - 1;
+        for (i, &d) in distances.iter().enumerate() {
+            if d > 0.0 && target < d {
+                chosen = i;
+                break;
+            }
+            target -= d;
+        }
+
+        let center = points[chosen];
+        for (d, p) in distances.iter_mut().zip(points) {
+            *d = d.min(distance2(p, &center));
+        }
+        centers.push(center);
+    }
+
+    centers
+}
+
+fn nearest(centers: &[[f32; 3]], point: &[f32; 3]) -> usize {
+    centers
+        .iter()
+        .map(|c| distance2(c, point))
+        .enumerate()
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map_or(0, |(i, _)| i)
+}
+
+fn distance2(a: &[f32; 3], b: &[f32; 3]) -> f32 {
+    (a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2)
+}
+
+/// a tiny deterministic rng, so results don't depend on a platform source of randomness
+pub(crate) struct SplitMix64(pub(crate) u64);
+
+impl SplitMix64 {
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// a float in [0, 1)
+    pub(crate) fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+}
@@ -0,0 +1,8 @@
+use rayon::prelude::*;
+
+use crate::Color;
+
+/// converts every color in `src` on the rayon thread pool. gives the same result as `src.iter().map(|&c| B::from(c)).collect()`, just spread over every core, which pays off for multi megapixel images
+pub fn par_convert<A: Color + Send + Sync, B: From<A> + Send>(src: &[A]) -> Vec<B> {
+    src.par_iter().map(|&color| B::from(color)).collect()
+}
@@ -0,0 +1,25 @@
+use crate::Color;
+
+/// the worst round trip error from `A` to `B` and back, as the largest difference in any channel of `A`. it samples a grid of `samples` evenly spaced values per channel over [0, 1]³ in `A`, so with `A` as [srgb](crate::srgb) that's the srgb cube
+///
+/// useful for pinning down the precision a pipeline can expect in its own tests. `samples` below 2 just checks the corner at 0
+pub fn max_roundtrip_error<A: Color, B: From<A> + Into<A>>(samples: usize) -> f32 {
+    let samples = samples.max(1);
+    let step = 1.0 / (samples - 1).max(1) as f32;
+    let mut worst = 0.0f32;
+
+    for i in 0..samples {
+        for j in 0..samples {
+            for k in 0..samples {
+                let original = [i, j, k].map(|n| n as f32 * step);
+                let back: [f32; 3] = Into::<A>::into(B::from(A::from(original))).into();
+
+                for (a, b) in original.iter().zip(back) {
+                    worst = worst.max((a - b).abs());
+                }
+            }
+        }
+    }
+
+    worst
+}
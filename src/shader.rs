@@ -0,0 +1,121 @@
+//! the conversions in this crate as glsl and wgsl source, so shaders can use the exact same constants as the rust side. each function takes and returns a `vec3`
+
+/// glsl `vec3 srgb_to_linear(vec3 c)`
+pub fn srgb_to_linear_glsl() -> &'static str {
+    "vec3 srgb_to_linear(vec3 c) {
+    vec3 low = c / 12.92;
+    vec3 high = pow((c + 0.055) / 1.055, vec3(2.4));
+    return mix(low, high, step(0.04045, c));
+}
+"
+}
+
+/// glsl `vec3 linear_to_srgb(vec3 c)`
+pub fn linear_to_srgb_glsl() -> &'static str {
+    "vec3 linear_to_srgb(vec3 c) {
+    vec3 low = c * 12.92;
+    vec3 high = 1.055 * pow(c, vec3(1.0 / 2.4)) - 0.055;
+    return mix(low, high, step(0.0031308, c));
+}
+"
+}
+
+/// glsl `vec3 linear_to_oklab(vec3 c)`, taking linear rgb
+pub fn linear_to_oklab_glsl() -> &'static str {
+    "vec3 linear_to_oklab(vec3 c) {
+    float l = 0.4122214708 * c.r + 0.5363325363 * c.g + 0.0514459929 * c.b;
+    float m = 0.2119034982 * c.r + 0.6806995451 * c.g + 0.1073969566 * c.b;
+    float s = 0.0883024619 * c.r + 0.2817188376 * c.g + 0.6299787005 * c.b;
+
+    l = sign(l) * pow(abs(l), 1.0 / 3.0);
+    m = sign(m) * pow(abs(m), 1.0 / 3.0);
+    s = sign(s) * pow(abs(s), 1.0 / 3.0);
+
+    return vec3(
+        0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+        1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+        0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s
+    );
+}
+"
+}
+
+/// glsl `vec3 oklab_to_linear(vec3 c)`, returning linear rgb
+pub fn oklab_to_linear_glsl() -> &'static str {
+    "vec3 oklab_to_linear(vec3 c) {
+    float l = c.x + 0.3963377774 * c.y + 0.2158037573 * c.z;
+    float m = c.x - 0.1055613458 * c.y - 0.0638541728 * c.z;
+    float s = c.x - 0.0894841775 * c.y - 1.2914855480 * c.z;
+
+    l = l * l * l;
+    m = m * m * m;
+    s = s * s * s;
+
+    return vec3(
+        4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+        -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+        -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s
+    );
+}
+"
+}
+
+/// wgsl `fn srgb_to_linear(c: vec3<f32>) -> vec3<f32>`
+pub fn srgb_to_linear_wgsl() -> &'static str {
+    "fn srgb_to_linear(c: vec3<f32>) -> vec3<f32> {
+    let low = c / 12.92;
+    let high = pow((c + 0.055) / 1.055, vec3<f32>(2.4));
+    return select(low, high, c >= vec3<f32>(0.04045));
+}
+"
+}
+
+/// wgsl `fn linear_to_srgb(c: vec3<f32>) -> vec3<f32>`
+pub fn linear_to_srgb_wgsl() -> &'static str {
+    "fn linear_to_srgb(c: vec3<f32>) -> vec3<f32> {
+    let low = c * 12.92;
+    let high = 1.055 * pow(c, vec3<f32>(1.0 / 2.4)) - 0.055;
+    return select(low, high, c >= vec3<f32>(0.0031308));
+}
+"
+}
+
+/// wgsl `fn linear_to_oklab(c: vec3<f32>) -> vec3<f32>`, taking linear rgb
+pub fn linear_to_oklab_wgsl() -> &'static str {
+    "fn linear_to_oklab(c: vec3<f32>) -> vec3<f32> {
+    let lms = vec3<f32>(
+        0.4122214708 * c.r + 0.5363325363 * c.g + 0.0514459929 * c.b,
+        0.2119034982 * c.r + 0.6806995451 * c.g + 0.1073969566 * c.b,
+        0.0883024619 * c.r + 0.2817188376 * c.g + 0.6299787005 * c.b,
+    );
+
+    let lms_ = sign(lms) * pow(abs(lms), vec3<f32>(1.0 / 3.0));
+
+    return vec3<f32>(
+        0.2104542553 * lms_.x + 0.7936177850 * lms_.y - 0.0040720468 * lms_.z,
+        1.9779984951 * lms_.x - 2.4285922050 * lms_.y + 0.4505937099 * lms_.z,
+        0.0259040371 * lms_.x + 0.7827717662 * lms_.y - 0.8086757660 * lms_.z,
+    );
+}
+"
+}
+
+/// wgsl `fn oklab_to_linear(c: vec3<f32>) -> vec3<f32>`, returning linear rgb
+pub fn oklab_to_linear_wgsl() -> &'static str {
+    "fn oklab_to_linear(c: vec3<f32>) -> vec3<f32> {
+    let lms_ = vec3<f32>(
+        c.x + 0.3963377774 * c.y + 0.2158037573 * c.z,
+        c.x - 0.1055613458 * c.y - 0.0638541728 * c.z,
+        c.x - 0.0894841775 * c.y - 1.2914855480 * c.z,
+    );
+
+    let lms = lms_ * lms_ * lms_;
+
+    return vec3<f32>(
+        4.0767416621 * lms.x - 3.3077115913 * lms.y + 0.2309699292 * lms.z,
+        -1.2684380046 * lms.x + 2.6097574011 * lms.y - 0.3413193965 * lms.z,
+        -0.0041960863 * lms.x - 0.7034186147 * lms.y + 1.7076147010 * lms.z,
+    );
+}
+"
+}
@@ -0,0 +1,51 @@
+use crate::{rgb, srgb};
+
+/// summary statistics over a slice of pixels, from [color_stats]. everything is measured in linear rgb
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ColorStats {
+    /// the mean of each linear channel
+    pub mean: rgb,
+    /// the population variance of each linear channel
+    pub variance: rgb,
+    /// the mean [relative luminance](srgb::relative_luminance)
+    pub mean_luminance: f32,
+    pub min_luminance: f32,
+    pub max_luminance: f32,
+}
+
+/// computes [ColorStats] for the pixels in one pass. an empty slice gives all zeros
+pub fn color_stats(pixels: &[srgb]) -> ColorStats {
+    if pixels.is_empty() {
+        return ColorStats::default();
+    }
+
+    let mut sum = [0.0f64; 3];
+    let mut sum_sq = [0.0f64; 3];
+    let mut luminance_sum = 0.0f64;
+    let (mut min_luminance, mut max_luminance) = (f32::INFINITY, f32::NEG_INFINITY);
+
+    for pixel in pixels {
+        let channels: [f32; 3] = rgb::from(*pixel).into();
+        for (i, c) in channels.into_iter().enumerate() {
+            sum[i] += c as f64;
+            sum_sq[i] += c as f64 * c as f64;
+        }
+
+        let luminance = pixel.relative_luminance();
+        luminance_sum += luminance as f64;
+        min_luminance = min_luminance.min(luminance);
+        max_luminance = max_luminance.max(luminance);
+    }
+
+    let n = pixels.len() as f64;
+    let mean = sum.map(|s| s / n);
+    let variance = [0, 1, 2].map(|i| (sum_sq[i] / n - mean[i] * mean[i]).max(0.0) as f32);
+
+    ColorStats {
+        mean: mean.map(|m| m as f32).into(),
+        variance: variance.into(),
+        mean_luminance: (luminance_sum / n) as f32,
+        min_luminance,
+        max_luminance,
+    }
+}
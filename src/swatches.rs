@@ -0,0 +1,85 @@
+use crate::{oklch, palette, srgb};
+
+/// how many clusters [palette_swatches] splits the image into before picking swatches from them
+const CLUSTERS: usize = 16;
+
+/// the oklch chroma that counts as fully vivid when scoring swatches. roughly a saturated srgb primary
+const VIVID_CHROMA: f32 = 0.2;
+
+/// how much closeness in lightness, closeness in chroma and cluster size each count towards a swatch's score
+const WEIGHTS: (f32, f32, f32) = (0.52, 0.24, 0.24);
+
+/// the lightness and chroma a swatch looks for, as `(min, target, max)` in oklab lightness and in chroma relative to [VIVID_CHROMA]
+struct Target {
+    lightness: (f32, f32, f32),
+    chroma: (f32, f32, f32),
+}
+
+const DARK: (f32, f32, f32) = (0.0, 0.4, 0.55);
+const NORMAL: (f32, f32, f32) = (0.45, 0.6, 0.75);
+const LIGHT: (f32, f32, f32) = (0.7, 0.85, 1.0);
+const VIBRANT: (f32, f32, f32) = (0.35, 1.0, f32::INFINITY);
+const MUTED: (f32, f32, f32) = (0.0, 0.3, 0.4);
+
+/// the named swatches [palette_swatches] picks out of an image, modeled after android's palette api. a swatch is None if no part of the image fits it
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Swatches {
+    pub vibrant: Option<srgb>,
+    pub light_vibrant: Option<srgb>,
+    pub dark_vibrant: Option<srgb>,
+    pub muted: Option<srgb>,
+    pub light_muted: Option<srgb>,
+    pub dark_muted: Option<srgb>,
+}
+
+/// picks [Swatches] out of an image for theming, the way android's palette does. the pixels are clustered in oklab, then each swatch takes the cluster that best matches its lightness and chroma targets, favoring larger clusters. a cluster is used for one swatch at most, filled in the order the fields are listed
+pub fn palette_swatches(pixels: &[srgb]) -> Swatches {
+    let mut clusters: Vec<(oklch, usize)> =
+        palette::clusters(pixels, CLUSTERS, palette::DEFAULT_SEED)
+            .into_iter()
+            .filter(|&(_, size)| size > 0)
+            .map(|(center, size)| (center.into(), size))
+            .collect();
+    let largest = clusters.first().map_or(1, |&(_, size)| size) as f32;
+
+    let mut pick = |lightness, chroma| {
+        let target = Target { lightness, chroma };
+        let (i, _) = clusters
+            .iter()
+            .enumerate()
+            .filter_map(|(i, &(color, size))| {
+                Some((i, target.score(color, size as f32 / largest)?))
+            })
+            .max_by(|a, b| a.1.total_cmp(&b.1))?;
+
+        Some(clusters.remove(i).0.gamut_map())
+    };
+
+    Swatches {
+        vibrant: pick(NORMAL, VIBRANT),
+        light_vibrant: pick(LIGHT, VIBRANT),
+        dark_vibrant: pick(DARK, VIBRANT),
+        muted: pick(NORMAL, MUTED),
+        light_muted: pick(LIGHT, MUTED),
+        dark_muted: pick(DARK, MUTED),
+    }
+}
+
+impl Target {
+    /// how well a cluster fits, or None if it's outside of the ranges. `population` is the cluster's size relative to the largest
+    fn score(&self, color: oklch, population: f32) -> Option<f32> {
+        let chroma = color.c / VIVID_CHROMA;
+        let (l_min, l_target, l_max) = self.lightness;
+        let (c_min, c_target, c_max) = self.chroma;
+        if !(l_min..=l_max).contains(&color.l) || !(c_min..=c_max).contains(&chroma) {
+            return None;
+        }
+
+        let (l_weight, c_weight, population_weight) = WEIGHTS;
+        Some(
+            l_weight * (1.0 - (color.l - l_target).abs())
+                + c_weight * (1.0 - (chroma - c_target).abs())
+                + population_weight * population,
+        )
+    }
+}
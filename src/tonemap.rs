@@ -0,0 +1,80 @@
+use crate::{rgb, srgb};
+
+/// the operators [rgb::tonemap] can use to compress hdr values into the displayable range
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ToneMap {
+    /// x / (1 + x) per channel. close to the identity for dark values and approaches 1.0 slowly, so highlights look flat
+    #[default]
+    Reinhard,
+    /// krzysztof narkowicz's fit of the aces filmic curve. adds contrast with a toe in the shadows and a soft shoulder, so dark values come out darker than they went in. values above about 7.2 clip to 1.0
+    AcesFilmic,
+}
+
+impl ToneMap {
+    fn apply(self, x: f32) -> f32 {
+        let x = x.max(0.0);
+
+        match self {
+            ToneMap::Reinhard => x / (1.0 + x),
+            ToneMap::AcesFilmic => {
+                let (a, b, c, d, e) = (2.51, 0.03, 2.43, 0.59, 0.14);
+                ((x * (a * x + b)) / (x * (c * x + d) + e)).clamp(0.0, 1.0)
+            }
+        }
+    }
+}
+
+impl rgb {
+    /// compresses linear values in [0, ∞) into [0, 1] with the given operator, then encodes to srgb. negative channels are treated as 0
+    pub fn tonemap(self, op: ToneMap) -> srgb {
+        rgb::from(<[f32; 3]>::from(self).map(|c| op.apply(c))).into()
+    }
+
+    /// brings an out of range color into [0, 1] by moving it towards the gray of the same luminance until every channel fits, instead of clipping channels one by one, which shifts the hue of bright highlights. colors that already fit are only encoded
+    ///
+    /// luminance is kept exactly unless it's above 1.0 or below 0.0 itself, in which case the result is white or black
+    pub fn desaturate_to_gamut(self) -> srgb {
+        let luminance = 0.2126 * self.r + 0.7152 * self.g + 0.0722 * self.b;
+        if luminance >= 1.0 {
+            return srgb::WHITE;
+        }
+        if luminance <= 0.0 {
+            return srgb::BLACK;
+        }
+
+        let channels: [f32; 3] = self.into();
+        // the largest fraction of the way from the gray to the color that keeps every channel in range
+        let k = channels.iter().fold(1.0f32, |k, &c| {
+            if c > 1.0 {
+                k.min((1.0 - luminance) / (c - luminance))
+            } else if c < 0.0 {
+                k.min(luminance / (luminance - c))
+            } else {
+                k
+            }
+        });
+
+        rgb::from(channels.map(|c| (luminance + (c - luminance) * k).clamp(0.0, 1.0))).into()
+    }
+}
+
+impl srgb {
+    /// rolls channels above `knee` off smoothly towards 1.0 instead of clipping them flat. the curve is exponential, meets the identity at `knee` with the same slope and never quite reaches 1.0, so bright values stay distinct. channels at or below `knee` are untouched
+    ///
+    /// a `knee` of 1.0 or more leaves no room to roll off, and clips at 1.0 instead
+    pub fn soft_clip(self, knee: f32) -> srgb {
+        let shoulder = 1.0 - knee;
+
+        <[f32; 3]>::from(self)
+            .map(|c| {
+                if c <= knee {
+                    c
+                } else if shoulder <= 0.0 {
+                    c.min(1.0)
+                } else {
+                    knee + shoulder * (1.0 - (-(c - knee) / shoulder).exp())
+                }
+            })
+            .into()
+    }
+}
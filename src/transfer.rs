@@ -0,0 +1,86 @@
+use crate::{rgb, srgb};
+
+// the parameters of the piecewise srgb curve, in f64. the runtime conversions between srgb and rgb and the const curve behind [SRGB_TO_LINEAR_LUT] both read them from here, so the two can't drift apart
+
+/// the offset of the power segment
+pub(crate) const OFFSET: f64 = 0.055;
+/// the scale of the power segment, 1 + [OFFSET] so the curve ends at exactly 1.0
+pub(crate) const SCALE: f64 = 1.0 + OFFSET;
+/// the slope of the linear segment near black
+pub(crate) const LINEAR_SLOPE: f64 = 12.92;
+/// the exponent of the power segment
+pub(crate) const EXPONENT: f64 = 2.4;
+/// the encoded value where decoding switches from the linear segment to the power segment
+pub(crate) const DECODE_THRESHOLD: f64 = 0.04045;
+/// the linear value where encoding switches from the linear segment to the power segment
+pub(crate) const ENCODE_THRESHOLD: f64 = 0.0031308;
+
+/// maps every 8-bit srgb value to its linear value, so decoding 8-bit images needs no `powf` per pixel. index it with the byte directly
+pub const SRGB_TO_LINEAR_LUT: [f32; 256] = {
+    let mut lut = [0.0; 256];
+
+    let mut i = 0;
+    while i < 256 {
+        lut[i] = to_linear_const(i as f64 / 255.0) as f32;
+        i += 1;
+    }
+
+    lut
+};
+
+impl rgb {
+    /// decodes 8-bit srgb channels into linear rgb with [SRGB_TO_LINEAR_LUT]
+    pub const fn from_u8_linear(bytes: [u8; 3]) -> rgb {
+        rgb {
+            r: SRGB_TO_LINEAR_LUT[bytes[0] as usize],
+            g: SRGB_TO_LINEAR_LUT[bytes[1] as usize],
+            b: SRGB_TO_LINEAR_LUT[bytes[2] as usize],
+        }
+    }
+}
+
+/// the exponent of the pure power law some engines and older tools use in place of the piecewise srgb curve
+const GAMMA_22: f32 = 2.2;
+
+impl rgb {
+    /// encodes with a pure 2.2 power law instead of the piecewise srgb curve, for matching pipelines that assume it. the two agree closely in the midtones but pull apart in the shadows. negative channels are mirrored around zero
+    pub fn to_srgb_gamma22(self) -> srgb {
+        <[f32; 3]>::from(self)
+            .map(|x| x.signum() * x.abs().powf(1.0 / GAMMA_22))
+            .into()
+    }
+}
+
+impl srgb {
+    /// decodes with a pure 2.2 power law instead of the piecewise srgb curve. the inverse of [rgb::to_srgb_gamma22]
+    pub fn to_rgb_gamma22(self) -> rgb {
+        <[f32; 3]>::from(self)
+            .map(|x| x.signum() * x.abs().powf(GAMMA_22))
+            .into()
+    }
+}
+
+/// the srgb decoding curve for x in [0, 1], without `powf` so it can run in a const context
+const fn to_linear_const(x: f64) -> f64 {
+    if x >= DECODE_THRESHOLD {
+        let base = (x + OFFSET) / SCALE;
+        // base^2.4 = base^2 * (base^(1/5))^2
+        let root = fifth_root(base);
+        base * base * root * root
+    } else {
+        x / LINEAR_SLOPE
+    }
+}
+
+/// newton's method for y^5 = x, for x in (0, 1]. starting from 1.0 the iterates shrink monotonically towards the root, so it stops once they stop shrinking
+const fn fifth_root(x: f64) -> f64 {
+    let mut y = 1.0;
+
+    loop {
+        let next = (4.0 * y + x / (y * y * y * y)) / 5.0;
+        if next >= y {
+            return y;
+        }
+        y = next;
+    }
+}
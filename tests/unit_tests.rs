@@ -1,4 +1,24 @@
-use tinycolors::{hsl, hsv, okhsl, oklab, rgb, srgb};
+use tinycolors::{
+    Alpha, AseError, BlendMode, Color, ColorError, ColorStats, CssColor, CubeError, GplError,
+    Gradient, HashableColor, Intent, Lut3D, RoundMode, SRGB_TO_LINEAR_LUT, ToneMap, YCbCrMatrix,
+    YCbCrRange, accessible_pair, ansi_pair, average, blend3, cielab, cielch, cmyk, color_stats,
+    diverging, downsample_2x, extract_palette, gamut_volume, hsi, hsl, hsv, hue_distance,
+    hue_wheel, max_roundtrip_error, mix_linear, most_readable, named_colors, normalize_lightness,
+    okhsl, okhsv, oklab, oklch, palette_swatches, parse_ase, parse_css_color, parse_cube,
+    parse_gpl, quantize_dithered, rgb, smoothstep, sort_by_luminance, sort_perceptual, srgb, srgba,
+    steps, to_gpl, weighted_average, xyy, xyz, ycbcr,
+};
+
+fn assert_close(a: impl Into<[f32; 3]>, b: impl Into<[f32; 3]>, epsilon: f32) {
+    let (a, b): ([f32; 3], [f32; 3]) = (a.into(), b.into());
+
+    for i in 0..3 {
+        assert!(
+            (a[i] - b[i]).abs() <= epsilon,
+            "{a:?} and {b:?} differ by more than {epsilon}"
+        );
+    }
+}
 
 #[test]
 fn rgb_to_srgb() {
@@ -121,9 +141,9 @@ fn rgb_to_oklab() {
 
     assert_eq!(
         oklab {
-            l: 0.7016732,
-            a: 0.27456677,
-            b: -0.16915637
+            l: 0.6279554,
+            a: 0.22486295,
+            b: 0.1258463
         },
         oklab::from(color)
     );
@@ -319,3 +339,2323 @@ fn srgb_to_hsv() {
         hsv::from(color)
     );
 }
+
+#[test]
+fn cielch_to_cielab() {
+    let color = cielab {
+        l: 53.24,
+        a: 80.09,
+        b: 67.2,
+    };
+
+    assert_close(color, cielab::from(cielch::from(color)), 1e-4);
+
+    let color = cielab::from(srgb::WHITE);
+    assert_close(color, [100.0, 0.0, 0.0], 1e-3);
+    assert_eq!(cielch::from(color).h, 0.0);
+
+    let color = cielch {
+        l: 50.0,
+        c: 40.0,
+        h: 0.25,
+    };
+    assert_close(cielab::from(color), [50.0, 0.0, 40.0], 1e-4);
+}
+
+#[test]
+fn srgb_to_hsi() {
+    assert_close(hsi::from(srgb::RED), [0.0, 1.0, 1.0 / 3.0], 1e-6);
+    assert_close(hsi::from(srgb::GREEN), [1.0 / 3.0, 1.0, 1.0 / 3.0], 1e-6);
+    assert_close(hsi::from(srgb::BLUE), [2.0 / 3.0, 1.0, 1.0 / 3.0], 1e-6);
+
+    let gray = srgb {
+        r: 0.5,
+        g: 0.5,
+        b: 0.5,
+    };
+    assert_eq!(
+        hsi {
+            h: 0.0,
+            s: 0.0,
+            i: 0.5
+        },
+        hsi::from(gray)
+    );
+
+    assert_eq!(hsi::from(srgb::BLACK), hsi::default());
+
+    let color = srgb {
+        r: 0.2,
+        g: 0.7,
+        b: 0.4,
+    };
+    assert_close(color, srgb::from(hsi::from(color)), 1e-5);
+}
+
+#[test]
+fn srgb_to_ycbcr() {
+    let (bt709, bt601) = (YCbCrMatrix::Bt709, YCbCrMatrix::Bt601);
+    let (full, limited) = (YCbCrRange::Full, YCbCrRange::Limited);
+
+    let white = ycbcr::from_srgb(srgb::WHITE, bt709, limited);
+    assert_close(white, [235.0 / 255.0, 128.0 / 255.0, 128.0 / 255.0], 1e-6);
+
+    let black = ycbcr::from_srgb(srgb::BLACK, bt709, limited);
+    assert_close(black, [16.0 / 255.0, 128.0 / 255.0, 128.0 / 255.0], 1e-6);
+
+    let white = ycbcr::from_srgb(srgb::WHITE, bt709, full);
+    assert_close(white, [1.0, 128.0 / 255.0, 128.0 / 255.0], 1e-6);
+
+    let gray = srgb {
+        r: 0.5,
+        g: 0.5,
+        b: 0.5,
+    };
+    assert_close(
+        ycbcr::from_srgb(gray, bt601, limited),
+        [125.5 / 255.0, 128.0 / 255.0, 128.0 / 255.0],
+        1e-6,
+    );
+
+    // the matrices only differ once there's chroma to weigh
+    let red_709 = ycbcr::from_srgb(srgb::RED, bt709, full);
+    let red_601 = ycbcr::from_srgb(srgb::RED, bt601, full);
+    assert_close(
+        [red_709.y, red_601.y, red_709.cr],
+        [0.2126, 0.299, 1.0 + 0.5 / 255.0],
+        1e-6,
+    );
+
+    let color = srgb {
+        r: 0.9,
+        g: 0.3,
+        b: 0.6,
+    };
+    for matrix in [bt601, bt709] {
+        for range in [full, limited] {
+            let encoded = ycbcr::from_srgb(color, matrix, range);
+            assert_close(color, encoded.to_srgb(matrix, range), 1e-5);
+        }
+    }
+}
+
+#[test]
+fn srgb_gamut_excess() {
+    let color = srgb {
+        r: 1.2,
+        g: 0.5,
+        b: 0.0,
+    };
+    assert!((color.gamut_excess() - 0.2).abs() < 1e-6);
+    assert!(!color.is_in_gamut());
+
+    let color = srgb {
+        r: 0.5,
+        g: -0.3,
+        b: 1.1,
+    };
+    assert!((color.gamut_excess() - 0.3).abs() < 1e-6);
+
+    assert_eq!(srgb::WHITE.gamut_excess(), 0.0);
+    assert_eq!(srgb::BLACK.gamut_excess(), 0.0);
+    assert!(srgb::PURPLE.is_in_gamut());
+
+    let color = srgb {
+        r: f32::NAN,
+        g: 0.5,
+        b: 0.5,
+    };
+    assert_eq!(color.gamut_excess(), f32::INFINITY);
+    assert!(!color.is_in_gamut());
+    assert_eq!(color.clipping_channels(), [true, false, false]);
+    assert!(!srgb::from([0.5, f32::INFINITY, 0.5]).is_in_gamut());
+}
+
+#[test]
+fn average_in_linear() {
+    let gray = average(&[srgb::BLACK, srgb::WHITE]);
+    assert_close(gray, [0.735357; 3], 1e-5);
+
+    assert_eq!(average(&[srgb::RED]), srgb::RED);
+    assert_eq!(average(&[]), srgb::BLACK);
+
+    let color = weighted_average(&[(srgb::BLACK, 3.0), (srgb::WHITE, 1.0)]);
+    assert_close(color, srgb::from(rgb::from([0.25; 3])), 1e-6);
+
+    assert_eq!(weighted_average(&[(srgb::WHITE, 0.0)]), srgb::BLACK);
+}
+
+#[test]
+fn extract_two_color_palette() {
+    let teal = srgb {
+        r: 0.1,
+        g: 0.6,
+        b: 0.55,
+    };
+    let orange = srgb {
+        r: 0.95,
+        g: 0.5,
+        b: 0.1,
+    };
+
+    let mut pixels = vec![teal; 60];
+    pixels.extend(vec![orange; 40]);
+    // a little noise around each color
+    for (i, pixel) in pixels.iter_mut().enumerate() {
+        let offset = (i % 5) as f32 * 0.01 - 0.02;
+        pixel.g += offset;
+    }
+
+    let palette = extract_palette(&pixels, 2);
+    assert_eq!(palette.len(), 2);
+    assert_close(palette[0], teal, 0.01);
+    assert_close(palette[1], orange, 0.01);
+
+    assert_eq!(extract_palette(&pixels, 2), palette);
+    let single = extract_palette(&[srgb::RED; 4], 3);
+    assert_eq!(single.len(), 1);
+    assert_close(single[0], srgb::RED, 1e-5);
+    assert!(extract_palette(&[], 3).is_empty());
+    assert!(extract_palette(&pixels, 0).is_empty());
+}
+
+#[test]
+fn tint_shade_tone() {
+    let color = srgb {
+        r: 0.2,
+        g: 0.4,
+        b: 0.8,
+    };
+
+    let scale: Vec<f32> = (0..5)
+        .map(|i| oklab::from(color.tint(i as f32 / 4.0)).l)
+        .collect();
+    assert!(scale.windows(2).all(|w| w[0] < w[1]), "{scale:?}");
+
+    assert_close(color.tint(0.0), color, 1e-6);
+    assert_close(color.tint(1.0), srgb::WHITE, 1e-6);
+    assert_close(color.shade(0.0), color, 1e-6);
+    assert_close(color.shade(1.0), srgb::BLACK, 1e-6);
+    assert_close(color.tone(1.0), [0.5; 3], 1e-6);
+    assert!(oklab::from(color.shade(0.5)).l < oklab::from(color).l);
+}
+
+#[test]
+fn tonal_palette() {
+    let seed = srgb {
+        r: 0.4,
+        g: 0.3,
+        b: 0.9,
+    };
+
+    let tones = seed.tonal_palette();
+    assert_close(tones[0], srgb::BLACK, 1e-3);
+    assert_close(tones[12], srgb::WHITE, 1e-3);
+
+    let lightness: Vec<f32> = tones.iter().map(|&tone| oklab::from(tone).l).collect();
+    assert!(lightness.windows(2).all(|w| w[0] < w[1]), "{lightness:?}");
+    assert!(tones.iter().all(srgb::is_in_gamut));
+
+    // a mid tone keeps the seed's hue
+    let hue = oklch::from(seed).h;
+    assert!((oklch::from(tones[5]).h - hue).abs() < 1e-3);
+}
+
+#[test]
+fn oklch_gamut_map() {
+    let color = oklch {
+        l: 0.6,
+        c: 0.5,
+        h: 0.1,
+    };
+
+    let mapped = color.gamut_map();
+    assert!(mapped.is_in_gamut());
+
+    let mapped = oklch::from(mapped);
+    assert!((mapped.l - color.l).abs() < 1e-3);
+    assert!((mapped.h - color.h).abs() < 1e-3);
+    assert!(mapped.c < color.c);
+
+    let lab = oklab::from(srgb {
+        r: 0.2,
+        g: 0.5,
+        b: 0.7,
+    });
+    assert_close(lab, oklab::from(oklch::from(lab)), 1e-6);
+}
+
+#[test]
+fn non_finite_colors() {
+    let color = srgb {
+        r: f32::NAN,
+        g: 0.5,
+        b: f32::INFINITY,
+    };
+
+    assert!(!color.is_finite());
+    assert!(srgb::RED.is_finite());
+
+    assert_eq!(
+        color.sanitize(),
+        srgb {
+            r: 0.0,
+            g: 0.5,
+            b: 0.0
+        }
+    );
+
+    assert_eq!(
+        color.try_into_checked::<oklab>(),
+        Err(ColorError::NonFinite)
+    );
+    assert_eq!(
+        srgb::RED.try_into_checked::<hsl>(),
+        Ok(hsl::from(srgb::RED))
+    );
+}
+
+#[test]
+fn chained_edit() {
+    let color = srgb {
+        r: 0.2,
+        g: 0.4,
+        b: 0.8,
+    };
+
+    let edited = color
+        .edit()
+        .lighten(0.1)
+        .saturate(0.2)
+        .rotate_hue(30.0 / 360.0)
+        .finish();
+
+    let mut expected = oklch::from(color);
+    expected.l += 0.1;
+    expected.c *= 1.2;
+    expected.h = (expected.h + 30.0 / 360.0).rem_euclid(1.0);
+    assert_eq!(edited, expected.gamut_map());
+
+    assert_eq!(color.lighten(0.1), color.edit().lighten(0.1).finish());
+    assert_close(color.edit().finish(), color, 1e-5);
+    assert!(oklch::from(color.desaturate(1.0)).c < 1e-4);
+}
+
+#[test]
+fn upstream_okhsl_types() {
+    let upstream = ::okhsl::Okhsl {
+        h: 0.25,
+        s: 0.5,
+        l: 0.75,
+    };
+
+    let color = okhsl::from(upstream);
+    assert_eq!(
+        color,
+        okhsl {
+            h: 0.25,
+            s: 0.5,
+            l: 0.75
+        }
+    );
+
+    let back: ::okhsl::Okhsl = color.into();
+    assert_eq!(
+        (back.h, back.s, back.l),
+        (upstream.h, upstream.s, upstream.l)
+    );
+
+    let upstream = ::okhsl::Okhsv {
+        h: 0.5,
+        s: 0.25,
+        v: 1.0,
+    };
+    let back: ::okhsl::Okhsv = okhsv::from(upstream).into();
+    assert_eq!(
+        (back.h, back.s, back.v),
+        (upstream.h, upstream.s, upstream.v)
+    );
+
+    let lab = oklab::from(srgb::RED);
+    assert_eq!(oklab::from(::okhsl::Oklab::from(lab)), lab);
+}
+
+#[test]
+fn luminance_ordering() {
+    let mut colors = [srgb::WHITE, srgb::BLACK, srgb::RED];
+    sort_by_luminance(&mut colors);
+    assert_eq!(colors, [srgb::BLACK, srgb::RED, srgb::WHITE]);
+
+    // green is far brighter than blue, even though their srgb sums match
+    assert_eq!(
+        srgb::BLUE.cmp_luminance(&srgb::GREEN),
+        std::cmp::Ordering::Less
+    );
+    assert!((srgb::WHITE.relative_luminance() - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn hashable_color_keys() {
+    let mut cache = std::collections::HashMap::new();
+    cache.insert(HashableColor(srgb::RED), oklab::from(srgb::RED));
+
+    assert_eq!(
+        cache.get(&HashableColor(srgb::RED)),
+        Some(&oklab::from(srgb::RED))
+    );
+    assert_eq!(cache.get(&HashableColor(srgb::BLUE)), None);
+
+    let zero = srgb {
+        r: 0.0,
+        g: 0.0,
+        b: 0.0,
+    };
+    let negative_zero = srgb {
+        r: -0.0,
+        g: 0.0,
+        b: 0.0,
+    };
+    assert_eq!(HashableColor(zero), HashableColor(negative_zero));
+
+    let nan = srgb {
+        r: f32::NAN,
+        g: 0.0,
+        b: 0.0,
+    };
+    assert_eq!(HashableColor(nan), HashableColor(nan));
+}
+
+#[test]
+fn round_channels() {
+    let color = srgb {
+        r: 0.123456,
+        g: 0.50000006,
+        b: 0.987654,
+    };
+
+    assert_eq!(
+        color.round_to(2),
+        srgb {
+            r: 0.12,
+            g: 0.5,
+            b: 0.99
+        }
+    );
+    assert_eq!(color.round_to(0), srgb::from([0.0, 1.0, 1.0]));
+
+    let color = hsl {
+        h: 0.33333334,
+        s: 1.0,
+        l: 0.5,
+    };
+    assert_eq!(color.round_to(3).h, 0.333);
+}
+
+#[test]
+fn clamp_channels() {
+    let color = oklab {
+        l: 1.5,
+        a: -0.6,
+        b: 0.1,
+    };
+
+    assert_eq!(
+        color.clamp(-0.4, 1.0),
+        oklab {
+            l: 1.0,
+            a: -0.4,
+            b: 0.1
+        }
+    );
+
+    let color = hsl {
+        h: 1.25,
+        s: 0.5,
+        l: 0.5,
+    };
+    assert_eq!(color.wrap_hue().h, 0.25);
+    assert_eq!(
+        hsv {
+            h: -0.25,
+            s: 1.0,
+            v: 1.0
+        }
+        .wrap_hue()
+        .h,
+        0.75
+    );
+    assert_eq!(
+        hsv {
+            h: -1e-9,
+            s: 1.0,
+            v: 1.0
+        }
+        .wrap_hue()
+        .h,
+        0.0
+    );
+}
+
+#[cfg(feature = "palette-interop")]
+#[test]
+fn palette_interop() {
+    let upstream = palette::Srgb::new(1.0, 0.5, 0.25);
+
+    let color = srgb::from(upstream);
+    assert_eq!(
+        color,
+        srgb {
+            r: 1.0,
+            g: 0.5,
+            b: 0.25
+        }
+    );
+    assert_eq!(palette::Srgb::from(color), upstream);
+
+    let upstream = palette::Oklch::new(0.7, 0.1, 90.0);
+    let color = oklch::from(upstream);
+    assert_close(color, [0.7, 0.1, 0.25], 1e-6);
+
+    let back = palette::Oklch::from(color);
+    assert!((back.hue.into_positive_degrees() - 90.0).abs() < 1e-4);
+
+    // both crates agree on what srgb red is in oklab
+    let theirs: palette::Oklab = palette::IntoColor::into_color(palette::Srgb::new(1.0, 0.0, 0.0));
+    assert_close(oklab::from(theirs), oklab::from(srgb::RED), 1e-3);
+}
+
+#[test]
+fn srgba_alpha() {
+    let color = srgba::from(srgb::RED);
+    assert_eq!(<[f32; 4]>::from(color), [1.0, 0.0, 0.0, 1.0]);
+    assert_eq!(srgb::from(srgba::with_alpha(srgb::BLUE, 0.5)), srgb::BLUE);
+}
+
+#[cfg(feature = "egui")]
+#[test]
+fn egui_interop() {
+    use ecolor::Color32;
+
+    let color = Color32::from_rgb(255, 128, 0);
+    assert_close(srgb::from(color), [1.0, 128.0 / 255.0, 0.0], 1e-6);
+    assert_eq!(Color32::from(srgb::from(color)), color);
+    assert_eq!(srgba::from(color).a, 1.0);
+
+    let color = Color32::from_rgba_unmultiplied(255, 0, 0, 128);
+    let translucent = srgba::from(color);
+    assert_eq!(translucent.rgb(), srgb::RED);
+    assert_eq!(translucent.a, 128.0 / 255.0);
+    assert_eq!(Color32::from(translucent), color);
+
+    assert_eq!(Color32::from(srgb::WHITE), Color32::WHITE);
+}
+
+#[cfg(feature = "image")]
+#[test]
+fn image_interop() {
+    let color = srgb::from(image::Rgb([255, 128, 0]));
+    assert_close(color, [1.0, 128.0 / 255.0, 0.0], 1e-6);
+    assert_eq!(image::Rgb::from(color), image::Rgb([255, 128, 0]));
+
+    let out_of_gamut = srgb {
+        r: 1.5,
+        g: -0.2,
+        b: 0.5,
+    };
+    assert_eq!(image::Rgb::from(out_of_gamut), image::Rgb([255, 0, 128]));
+
+    let color = srgba::from(image::Rgba([0, 0, 255, 51]));
+    assert_close(color.rgb(), srgb::BLUE, 1e-6);
+    assert_eq!(color.a, 0.2);
+    assert_eq!(image::Rgba::from(color), image::Rgba([0, 0, 255, 51]));
+}
+
+#[test]
+fn wavelength_to_srgb() {
+    let red = srgb::from_wavelength(700.0);
+    assert!(red.r > 0.9 && red.g == 0.0 && red.b == 0.0, "{red:?}");
+
+    let blue = srgb::from_wavelength(470.0);
+    assert!(blue.b > blue.g && blue.b > blue.r, "{blue:?}");
+
+    let green = srgb::from_wavelength(530.0);
+    assert!(green.g > green.r && green.g > green.b, "{green:?}");
+
+    // fading out towards the edges of the spectrum
+    assert!(srgb::from_wavelength(770.0).r < red.r);
+
+    assert_eq!(srgb::from_wavelength(300.0), srgb::BLACK);
+    assert_eq!(srgb::from_wavelength(900.0), srgb::BLACK);
+}
+
+#[test]
+fn text_on_color() {
+    let navy = srgb {
+        r: 0.0,
+        g: 0.0,
+        b: 0.5,
+    };
+    assert!(navy.is_dark());
+    assert_eq!(navy.best_text_color(), srgb::WHITE);
+    assert_eq!(srgb::BLACK.best_text_color(), srgb::WHITE);
+
+    assert!(!srgb::YELLOW.is_dark());
+    assert_eq!(srgb::YELLOW.best_text_color(), srgb::BLACK);
+    assert_eq!(srgb::WHITE.best_text_color(), srgb::BLACK);
+
+    assert!((srgb::BLACK.contrast_ratio(&srgb::WHITE) - 21.0).abs() < 1e-4);
+    assert_eq!(srgb::RED.contrast_ratio(&srgb::RED), 1.0);
+}
+
+#[test]
+fn srgba_mix() {
+    let red = srgba::from(srgb::RED);
+    let clear_blue = srgba::with_alpha(srgb::BLUE, 0.0);
+
+    let mixed = red.mix(clear_blue, 0.5);
+    assert_close(mixed.rgb(), srgb::RED, 1e-6);
+    assert_eq!(mixed.a, 0.5);
+
+    let clear = srgba::TRANSPARENT;
+    let mixed = clear.mix(clear_blue, 0.5);
+    assert_eq!(mixed, srgba::TRANSPARENT);
+
+    assert_eq!(red.mix(clear_blue, 0.0), red);
+
+    // two opaque colors mix like the linear average
+    let mixed = srgba::from(srgb::BLACK).mix(srgba::from(srgb::WHITE), 0.5);
+    assert_close(mixed.rgb(), average(&[srgb::BLACK, srgb::WHITE]), 1e-6);
+}
+
+#[test]
+fn srgb_to_linear_lut() {
+    for (i, &linear) in SRGB_TO_LINEAR_LUT.iter().enumerate() {
+        let exact = rgb::from(srgb::from([i as f32 / 255.0; 3])).r;
+        assert!(
+            (linear - exact).abs() < 1e-6,
+            "entry {i}: {linear} != {exact}"
+        );
+    }
+
+    assert_eq!(SRGB_TO_LINEAR_LUT[0], 0.0);
+    assert_eq!(SRGB_TO_LINEAR_LUT[255], 1.0);
+
+    let color = rgb::from_u8_linear([255, 128, 0]);
+    assert_close(
+        color,
+        rgb::from(srgb::from([1.0, 128.0 / 255.0, 0.0])),
+        1e-6,
+    );
+}
+
+#[test]
+fn blend_modes() {
+    let color = srgb {
+        r: 0.8,
+        g: 0.3,
+        b: 0.2,
+    };
+    let gray = srgb::from([0.5; 3]);
+
+    // luminosity of a gray keeps the backdrop's hue
+    let blended = color.blend(gray, BlendMode::Luminosity);
+    assert!((hsl::from(blended).h - hsl::from(color).h).abs() < 1e-5);
+
+    // and color onto a gray takes the source's hue
+    let blended = gray.blend(color, BlendMode::Color);
+    assert!((hsl::from(blended).h - hsl::from(color).h).abs() < 1e-5);
+
+    // an out of range gray has nothing to clip towards, so it comes back as it is instead of NaN
+    for out_of_range in [srgb::gray(-0.2), srgb::gray(1.5)] {
+        let blended = gray.blend(out_of_range, BlendMode::Luminosity);
+        assert_close(blended, out_of_range, 1e-6);
+    }
+
+    assert_eq!(color.blend(color, BlendMode::Difference), srgb::BLACK);
+    assert_close(
+        color.blend(srgb::WHITE, BlendMode::Exclusion),
+        [0.2, 0.7, 0.8],
+        1e-6,
+    );
+    // a gray source has no hue or saturation to give, so only the backdrop's luminosity is left
+    assert_close(color.blend(gray, BlendMode::Hue), [0.439; 3], 1e-6);
+    assert_close(color.blend(gray, BlendMode::Saturation), [0.439; 3], 1e-6);
+
+    assert_eq!(color.blend(srgb::WHITE, BlendMode::Multiply), color);
+}
+
+#[test]
+fn smooth_gradient() {
+    let gradient = Gradient::new([
+        (0.0, oklab::from(srgb::BLACK)),
+        (0.4, oklab::from(srgb::RED)),
+        (1.0, oklab::from(srgb::YELLOW)),
+    ]);
+
+    // the biggest second difference of any channel, which spikes wherever the curve has a kink
+    let roughness = |sample: &dyn Fn(f32) -> oklab| {
+        let samples: Vec<[f32; 3]> = (0..=100).map(|i| sample(i as f32 / 100.0).into()).collect();
+        samples
+            .windows(3)
+            .flat_map(|w| (0..3).map(move |c| (w[0][c] - 2.0 * w[1][c] + w[2][c]).abs()))
+            .fold(0.0, f32::max)
+    };
+
+    let linear = roughness(&|t| gradient.sample(t));
+    let smooth = roughness(&|t| gradient.sample_smooth(t));
+    assert!(smooth < linear / 2.0, "{smooth} vs {linear}");
+
+    // both pass through the stops, and the ends aren't overshot
+    for (position, color) in gradient.stops() {
+        assert_close(gradient.sample_smooth(*position), *color, 1e-6);
+        assert_close(gradient.sample(*position), *color, 1e-6);
+    }
+    assert_eq!(gradient.sample_smooth(-1.0), oklab::from(srgb::BLACK));
+    for i in 0..=100 {
+        let l = gradient.sample_smooth(i as f32 / 100.0).l;
+        assert!((0.0..=1.0 + 1e-6).contains(&l));
+    }
+
+    // two stops are just linear
+    let gradient = Gradient::evenly_spaced([oklab::from(srgb::BLUE), oklab::from(srgb::WHITE)]);
+    for i in 0..=10 {
+        let t = i as f32 / 10.0;
+        assert_close(gradient.sample_smooth(t), gradient.sample(t), 1e-6);
+    }
+}
+
+#[test]
+fn lut_matches_exact_conversion() {
+    let lut = Lut3D::new(33, |c: srgb| oklab::from(c).into());
+
+    let mut worst = 0.0f32;
+    for r in 0..=20 {
+        for g in 0..=20 {
+            for b in 0..=20 {
+                let color = srgb::from([r as f32 / 20.0, g as f32 / 20.0, b as f32 / 20.0]);
+                let exact: [f32; 3] = oklab::from(color).into();
+                let looked_up: [f32; 3] = lut.apply(color);
+                for i in 0..3 {
+                    worst = worst.max((exact[i] - looked_up[i]).abs());
+                }
+            }
+        }
+    }
+    assert!(worst < 2e-3, "{worst}");
+
+    // the grid points themselves are exact
+    let lab: oklab = lut.apply(srgb::RED);
+    assert_close(lab, oklab::from(srgb::RED), 1e-6);
+}
+
+#[test]
+fn explicit_conversions() {
+    let color = srgb {
+        r: 0.9,
+        g: 0.4,
+        b: 0.1,
+    };
+
+    assert_eq!(color.to_srgb(), color);
+    assert_eq!(color.to_rgb(), rgb::from(color));
+    assert_eq!(color.to_oklab(), oklab::from(color));
+    assert_eq!(color.to_okhsl(), okhsl::from(color));
+    assert_eq!(color.to_okhsv(), okhsv::from(color));
+    assert_eq!(color.to_hsl(), hsl::from(color));
+    assert_eq!(color.to_hsv(), hsv::from(color));
+
+    let color = color.to_oklab();
+    assert_eq!(color.to_hsv().h, hsv::from(color).h);
+    assert_eq!(color.to_rgb(), rgb::from(color));
+    assert_eq!(oklch::from(color).to_srgb(), srgb::from(oklch::from(color)));
+    assert_eq!(
+        cielab::from(color).to_okhsl(),
+        okhsl::from(cielab::from(color))
+    );
+}
+
+/// every number literal in a shader source, in order. a `-` before a number negates it, whether it's a sign or a subtraction
+fn shader_numbers(source: &str) -> Vec<f32> {
+    let mut numbers = Vec::new();
+    let mut previous = "";
+
+    for word in source
+        .split(|c: char| c.is_whitespace() || "(),;".contains(c))
+        .filter(|word| !word.is_empty())
+    {
+        if let Ok(number) = word.parse::<f32>() {
+            numbers.push(if previous == "-" { -number } else { number });
+        }
+        previous = word;
+    }
+
+    numbers
+}
+
+#[test]
+fn shader_matrices_match_oklab_constants() {
+    use tinycolors::shader;
+
+    // the 1.0 / 3.0 of the cube root is the only other number in the sources
+    let coefficients = |source| -> Vec<f32> {
+        shader_numbers(source)
+            .into_iter()
+            .filter(|n| ![1.0, 3.0].contains(n))
+            .collect()
+    };
+
+    let forward: Vec<f32> = oklab::M1
+        .iter()
+        .chain(&oklab::M2)
+        .flatten()
+        .copied()
+        .collect();
+    assert_eq!(coefficients(shader::linear_to_oklab_glsl()), forward);
+    assert_eq!(coefficients(shader::linear_to_oklab_wgsl()), forward);
+
+    // the shaders write the first column of M2_INV as a bare `c.x`
+    assert!(oklab::M2_INV.iter().all(|row| row[0] == 1.0));
+    let inverse: Vec<f32> = oklab::M2_INV
+        .iter()
+        .flat_map(|row| &row[1..])
+        .chain(oklab::M1_INV.iter().flatten())
+        .copied()
+        .collect();
+    assert_eq!(coefficients(shader::oklab_to_linear_glsl()), inverse);
+    assert_eq!(coefficients(shader::oklab_to_linear_wgsl()), inverse);
+}
+
+#[test]
+fn shader_snippets_match() {
+    use tinycolors::shader;
+
+    // runs the curve the shaders describe, with the numbers parsed out of them, against the rust conversions
+    for source in [shader::srgb_to_linear_glsl(), shader::srgb_to_linear_wgsl()] {
+        let [slope, offset, scale, exponent, threshold] = shader_numbers(source)[..] else {
+            panic!("unexpected numbers in {source}");
+        };
+
+        for (i, &linear) in SRGB_TO_LINEAR_LUT.iter().enumerate() {
+            let c = i as f32 / 255.0;
+            let shader = if c >= threshold {
+                ((c + offset) / scale).powf(exponent)
+            } else {
+                c / slope
+            };
+            assert!((shader - linear).abs() < 1e-6, "{c}: {shader} != {linear}");
+        }
+    }
+
+    for source in [shader::linear_to_srgb_glsl(), shader::linear_to_srgb_wgsl()] {
+        let [slope, scale, one, exponent, offset, threshold] = shader_numbers(source)[..] else {
+            panic!("unexpected numbers in {source}");
+        };
+        assert_eq!(one, 1.0);
+
+        for i in 0..=1000 {
+            let c = i as f32 / 1000.0;
+            let shader = if c >= threshold {
+                scale * c.powf(one / exponent) + offset
+            } else {
+                c * slope
+            };
+            let expected = srgb::from(rgb::from([c; 3])).r;
+            assert!(
+                (shader - expected).abs() < 1e-6,
+                "{c}: {shader} != {expected}"
+            );
+        }
+    }
+}
+
+#[test]
+fn most_readable_candidate() {
+    let dark_gray = srgb::from([0.25; 3]);
+    let light_gray = srgb::from([0.75; 3]);
+
+    assert_eq!(
+        most_readable(srgb::WHITE, &[dark_gray, light_gray]),
+        Some(dark_gray)
+    );
+    assert_eq!(
+        most_readable(srgb::BLACK, &[dark_gray, light_gray]),
+        Some(light_gray)
+    );
+    assert_eq!(most_readable(srgb::WHITE, &[]), None);
+}
+
+#[test]
+fn nearest_palette_color() {
+    let palette = [srgb::BLACK, srgb::RED, srgb::WHITE];
+
+    assert_eq!(srgb::RED.nearest_in_palette(&palette), Some(1));
+    assert_eq!(srgb::from([0.1; 3]).nearest_in_palette(&palette), Some(0));
+    assert_eq!(
+        srgb::from([0.9, 0.8, 0.8]).nearest_in_palette(&palette),
+        Some(2)
+    );
+    assert_eq!(srgb::RED.nearest_in_palette(&[]), None);
+}
+
+#[test]
+fn dithered_quantization() {
+    let (width, height) = (32, 8);
+    let pixels: Vec<srgb> = (0..width * height)
+        .map(|i| srgb::from([0.3 + 0.2 * (i % width) as f32 / width as f32; 3]))
+        .collect();
+    let palette = [
+        srgb::BLACK,
+        srgb::from([1.0 / 3.0; 3]),
+        srgb::from([2.0 / 3.0; 3]),
+        srgb::WHITE,
+    ];
+
+    let distinct = |indices: &[usize]| {
+        let mut indices = indices.to_vec();
+        indices.sort();
+        indices.dedup();
+        indices.len()
+    };
+
+    let naive: Vec<usize> = pixels
+        .iter()
+        .map(|p| p.nearest_in_palette(&palette).unwrap())
+        .collect();
+    let dithered = quantize_dithered(&pixels, width, height, &palette);
+
+    assert_eq!(dithered.len(), pixels.len());
+    assert!(distinct(&dithered) > distinct(&naive));
+
+    // dithering keeps the average brightness of the image
+    let brightness = |colors: &[srgb]| average(colors).r;
+    let quantized: Vec<srgb> = dithered.iter().map(|&i| palette[i]).collect();
+    assert!((brightness(&quantized) - brightness(&pixels)).abs() < 0.01);
+
+    assert!(quantize_dithered(&pixels, width, height, &[]).is_empty());
+}
+
+#[test]
+fn premultiplied_alpha() {
+    let color = srgba {
+        r: 0.8,
+        g: 0.5,
+        b: 0.2,
+        a: 0.4,
+    };
+
+    let premultiplied = color.to_premultiplied_array();
+    let linear = rgb::from(color.rgb());
+    assert_close(
+        [premultiplied[0], premultiplied[1], premultiplied[2]],
+        [linear.r * 0.4, linear.g * 0.4, linear.b * 0.4],
+        1e-6,
+    );
+    assert_eq!(premultiplied[3], 0.4);
+
+    let back = srgba::from_premultiplied_array(premultiplied);
+    assert_close(back.rgb(), color.rgb(), 1e-5);
+    assert_eq!(back.a, color.a);
+
+    assert_eq!(
+        srgba::from_premultiplied_array([0.0; 4]),
+        srgba::TRANSPARENT
+    );
+}
+
+#[test]
+fn gamut_mapping_intents() {
+    let color = oklch {
+        l: 0.7,
+        c: 0.6,
+        h: 0.4,
+    };
+
+    let reduced = color.gamut_map_with(Intent::ChromaReduction);
+    let clipped = color.gamut_map_with(Intent::Clip);
+    let scaled = color.gamut_map_with(Intent::PerceptualScale);
+
+    for mapped in [reduced, clipped, scaled] {
+        assert!(mapped.is_in_gamut(), "{mapped:?}");
+    }
+
+    assert_eq!(reduced, color.gamut_map());
+    // only chroma reduction is guaranteed to keep both hue and lightness
+    let reduced = oklch::from(reduced);
+    assert!((reduced.h - color.h).abs() < 1e-3 && (reduced.l - color.l).abs() < 1e-3);
+    // scaling towards the gray keeps lightness, clipping doesn't
+    assert!((oklch::from(scaled).l - color.l).abs() < 0.05);
+    assert_ne!(oklch::from(clipped), reduced);
+
+    // an in gamut color passes through every intent unchanged
+    let inside = oklch::from(srgb::from([0.3, 0.5, 0.7]));
+    for intent in [
+        Intent::ChromaReduction,
+        Intent::Clip,
+        Intent::PerceptualScale,
+    ] {
+        assert_close(inside.gamut_map_with(intent), [0.3, 0.5, 0.7], 1e-5);
+    }
+}
+
+#[test]
+fn max_oklch_chroma() {
+    let red_hue = oklch::from(srgb::RED).h;
+    let c = oklch::max_chroma(0.5, red_hue);
+    assert!(c > 0.0);
+
+    let at_boundary = rgb::from(oklch {
+        l: 0.5,
+        c,
+        h: red_hue,
+    });
+    assert!(
+        [at_boundary.r, at_boundary.g, at_boundary.b]
+            .iter()
+            .all(|c| (-1e-4..=1.0 + 1e-4).contains(c))
+    );
+
+    let beyond = srgb::from(oklch {
+        l: 0.5,
+        c: c + 0.005,
+        h: red_hue,
+    });
+    assert!(!beyond.is_in_gamut());
+
+    // red itself sits right on the boundary
+    let red = oklch::from(srgb::RED);
+    assert!((oklch::max_chroma(red.l, red.h) - red.c).abs() < 1e-3);
+
+    assert_eq!(oklch::max_chroma(1.0, red_hue), 0.0);
+}
+
+#[cfg(feature = "half")]
+#[test]
+fn half_interop() {
+    use half::f16;
+
+    let color = rgb::from(srgb::from([0.9, 0.45, 0.1]));
+    let packed: [f16; 3] = color.into();
+    assert_eq!(packed[0], f16::from_f32(color.r));
+
+    // f16 has an 11 bit significand, so the relative error is at most 2^-11
+    let unpacked = rgb::from(packed);
+    assert_close(unpacked, color, 1e-3);
+
+    // hdr values above 1.0 survive
+    let bright = rgb::from([f16::from_f32(4.0); 3]);
+    assert_eq!(bright, rgb::from([4.0; 3]));
+}
+
+#[test]
+fn mix_toward_gray() {
+    let color = srgb::from([0.4, 0.1, 0.05]);
+    let lightness = oklab::from(color).l;
+
+    for amount in [0.0, 0.25, 0.5, 1.0] {
+        let muted = oklab::from(color.mix_gray(amount));
+        assert!((muted.l - lightness).abs() < 1e-4);
+    }
+
+    assert_close(color.mix_gray(0.0), color, 1e-5);
+
+    let gray = color.mix_gray(1.0);
+    assert!((gray.r - gray.g).abs() < 1e-5 && (gray.g - gray.b).abs() < 1e-5);
+    // unlike tone, the gray isn't pulled towards 0.5
+    assert!(gray.r < 0.3);
+}
+
+#[test]
+fn degree_and_percent_channels() {
+    assert_eq!(
+        hsl::from_degrees(210.0, 50.0, 40.0),
+        hsl {
+            h: 210.0 / 360.0,
+            s: 0.5,
+            l: 0.4
+        }
+    );
+    assert_eq!(
+        hsv::from_degrees(90.0, 25.0, 100.0),
+        hsv {
+            h: 0.25,
+            s: 0.25,
+            v: 1.0
+        }
+    );
+
+    let (h, s, l) = hsl::from_degrees(210.0, 50.0, 40.0).to_degrees();
+    assert_close([h, s, l], [210.0, 50.0, 40.0], 1e-4);
+    let (h, s, v) = hsv::from_degrees(300.0, 80.0, 20.0).to_degrees();
+    assert_close([h, s, v], [300.0, 80.0, 20.0], 1e-4);
+}
+
+#[test]
+fn gamma_22_transfer() {
+    let color = rgb::from([0.0, 0.2, 1.0]);
+    assert_close(color.to_srgb_gamma22().to_rgb_gamma22(), color, 1e-6);
+    assert_close(
+        color.to_srgb_gamma22(),
+        [0.0, 0.2f32.powf(1.0 / 2.2), 1.0],
+        1e-6,
+    );
+
+    // near the knee of the piecewise curve the linear segment keeps shadows brighter than the pure power law
+    let dark = srgb::from([0.04; 3]);
+    let (piecewise, gamma22) = (rgb::from(dark), dark.to_rgb_gamma22());
+    assert!(piecewise.r > gamma22.r);
+    assert!(piecewise.r - gamma22.r < 0.003);
+}
+
+#[test]
+fn pixel_statistics() {
+    let stats = color_stats(&[srgb::BLACK, srgb::WHITE]);
+    assert!((stats.mean_luminance - 0.5).abs() < 1e-6);
+    assert_eq!(stats.min_luminance, 0.0);
+    assert!((stats.max_luminance - 1.0).abs() < 1e-6);
+    assert_close(stats.mean, [0.5; 3], 1e-6);
+    assert_close(stats.variance, [0.25; 3], 1e-6);
+
+    let flat = color_stats(&[srgb::RED; 4]);
+    assert_close(flat.variance, [0.0; 3], 1e-6);
+
+    assert_eq!(color_stats(&[]), ColorStats::default());
+}
+
+#[test]
+fn hex_strings() {
+    assert_eq!(srgb::from_hex("#ff8040").unwrap().to_hex(), "#ff8040");
+    assert_eq!(srgb::from_hex("F84"), srgb::from_hex("#ff8844"));
+    assert_eq!(srgb::from_hex("#000"), Ok(srgb::BLACK));
+    assert_eq!(srgb::from_hex("#ff80"), Err(ColorError::InvalidHex));
+    assert_eq!(srgb::from_hex("#gg0000"), Err(ColorError::InvalidHex));
+    assert_eq!(srgb::from_hex("#+f+f+f"), Err(ColorError::InvalidHex));
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct Theme {
+        #[serde(with = "tinycolors::as_hex")]
+        accent: srgb,
+    }
+
+    let theme = Theme {
+        accent: srgb::from_hex("#ff8040").unwrap(),
+    };
+    let text = toml::to_string(&theme).unwrap();
+    assert_eq!(text.trim(), r##"accent = "#ff8040""##);
+    assert_eq!(toml::from_str::<Theme>(&text).unwrap().accent, theme.accent);
+
+    let err = toml::from_str::<Theme>(r#"accent = "orange""#)
+        .err()
+        .unwrap();
+    assert!(err.to_string().contains("invalid hex color"));
+}
+
+#[test]
+fn ansi_escape_pair() {
+    assert_eq!(
+        ansi_pair(srgb::RED, srgb::BLACK),
+        "\x1b[38;2;255;0;0;48;2;0;0;0m"
+    );
+}
+
+#[test]
+fn evenly_spaced_hue_wheel() {
+    let ring: Vec<oklch> = hue_wheel(12, 0.7, 0.1).map(oklch::from).collect();
+    assert_eq!(ring.len(), 12);
+
+    for pair in ring.windows(2) {
+        assert!((pair[1].h - pair[0].h - 1.0 / 12.0).abs() < 1e-3);
+        assert!((pair[0].l - 0.7).abs() < 1e-3);
+    }
+
+    // a chroma no srgb color reaches still gives valid colors
+    assert!(hue_wheel(6, 0.5, 0.5).all(|color| color.is_in_gamut()));
+    assert_eq!(hue_wheel(0, 0.5, 0.1).count(), 0);
+}
+
+#[test]
+fn warm_and_cool() {
+    assert!(srgb::RED.warmth() > 0.5);
+    assert!(srgb::from_hex("#ff8000").unwrap().warmth() > 0.5);
+    assert!(srgb::BLUE.warmth() < -0.5);
+    assert!(srgb::from_hex("#00c0ff").unwrap().warmth() < -0.5);
+    assert!(srgb::from([0.5; 3]).warmth().abs() < 1e-3);
+
+    for color in [srgb::RED, srgb::GREEN, srgb::BLUE, srgb::WHITE] {
+        assert!((-1.0..=1.0).contains(&color.warmth()));
+    }
+}
+
+#[test]
+fn rgb565_packing() {
+    assert_eq!(srgb::WHITE.to_rgb565(), 0xffff);
+    assert_eq!(srgb::BLACK.to_rgb565(), 0x0000);
+    assert_eq!(srgb::RED.to_rgb565(), 0xf800);
+    assert_eq!(srgb::GREEN.to_rgb565(), 0x07e0);
+    assert_eq!(srgb::BLUE.to_rgb565(), 0x001f);
+    assert_eq!(srgb::from(0xffff), srgb::WHITE);
+
+    // 5 bit channels are the coarsest, with steps of 1/31
+    let color = srgb::from([0.8, 0.4, 0.1]);
+    assert_close(srgb::from(color.to_rgb565()), color, 0.5 / 31.0 + 1e-6);
+}
+
+#[test]
+fn css_named_colors() {
+    // css 3 has 147 names, and css color 4 added rebeccapurple
+    assert_eq!(named_colors().count(), 148);
+
+    let find = |name| named_colors().find(|&(n, _)| n == name).map(|(_, c)| c);
+    assert_eq!(find("rebeccapurple"), srgb::from_hex("#663399").ok());
+    assert_eq!(find("white"), Some(srgb::WHITE));
+    assert_eq!(find("grey"), find("gray"));
+    assert_eq!(find("orange"), srgb::from_hex("#ffa500").ok());
+}
+
+#[test]
+fn recolor_to_hue() {
+    let red_hue = oklch::from(srgb::RED).h;
+    let blues: Vec<srgb> = (1..8)
+        .map(|i| {
+            oklch {
+                l: i as f32 / 8.0,
+                c: 0.05,
+                h: oklch::from(srgb::BLUE).h,
+            }
+            .gamut_map()
+        })
+        .collect();
+
+    for blue in blues {
+        let red = oklch::from(blue.recolor(red_hue));
+        assert!((red.l - oklch::from(blue).l).abs() < 1e-3);
+        assert!((red.h - red_hue).abs() < 1e-3);
+    }
+
+    let gray = srgb::from([0.5, 0.5, 0.502]);
+    assert_close(gray.recolor(red_hue), gray, 0.005);
+}
+
+#[test]
+fn okhsl_okhsv_direct() {
+    for color in [srgb::from([0.8, 0.3, 0.1]), srgb::from([0.1, 0.6, 0.9])] {
+        let hsl = okhsl::from(color);
+        let hsv = okhsv::from(color);
+
+        assert_close(okhsv::from(hsl), okhsv::from(oklab::from(hsl)), 1e-5);
+        assert_close(okhsl::from(hsv), okhsl::from(oklab::from(hsv)), 1e-5);
+        assert_close(okhsl::from(okhsv::from(hsl)), hsl, 1e-4);
+    }
+}
+
+#[test]
+fn roundtrip_precision() {
+    assert!(max_roundtrip_error::<srgb, oklab>(17) < 1e-4);
+    assert!(max_roundtrip_error::<srgb, rgb>(17) < 1e-5);
+    assert!(max_roundtrip_error::<srgb, cielab>(17) < 1e-3);
+    assert_eq!(max_roundtrip_error::<srgb, srgb>(5), 0.0);
+}
+
+#[test]
+fn downsample_checkerboard() {
+    let (width, height) = (4, 4);
+    let checkerboard: Vec<srgb> = (0..width * height)
+        .map(|i| {
+            if (i % width + i / width) % 2 == 0 {
+                srgb::BLACK
+            } else {
+                srgb::WHITE
+            }
+        })
+        .collect();
+
+    let (half, w, h) = downsample_2x(&checkerboard, width, height);
+    assert_eq!((w, h, half.len()), (2, 2, 4));
+    // half of full linear intensity, not 0.5 in srgb
+    for pixel in half {
+        assert_close(pixel, [0.735; 3], 1e-3);
+    }
+
+    // odd sizes keep the last column
+    let (half, w, h) = downsample_2x(&[srgb::WHITE, srgb::WHITE, srgb::RED], 3, 1);
+    assert_eq!((w, h), (2, 1));
+    assert_eq!(half[1], srgb::RED);
+
+    assert_eq!(downsample_2x(&[], 0, 0), (vec![], 0, 0));
+}
+
+#[test]
+fn oklab_matrices() {
+    // each pair of matrices multiplies out to the identity
+    for (m, inverse) in [(oklab::M1, oklab::M1_INV), (oklab::M2, oklab::M2_INV)] {
+        for (i, row) in inverse.iter().enumerate() {
+            for j in 0..3 {
+                let product: f32 = row.iter().zip(&m).map(|(a, m_row)| a * m_row[j]).sum();
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((product - expected).abs() < 1e-6);
+            }
+        }
+    }
+
+    // white has the same lms response in every cone
+    let lms = oklab::M1.map(|row| row.iter().sum::<f32>());
+    assert_close(lms, [1.0; 3], 1e-6);
+}
+
+#[test]
+fn linear_light_mix() {
+    assert_close(mix_linear(srgb::BLACK, srgb::WHITE, 0.5), [0.735; 3], 1e-3);
+    assert_close(mix_linear(srgb::RED, srgb::BLUE, 0.0), srgb::RED, 1e-6);
+    assert_close(mix_linear(srgb::RED, srgb::BLUE, 1.0), srgb::BLUE, 1e-6);
+}
+
+#[test]
+fn linear_luma() {
+    let gray = srgb::from([0.6; 3]);
+    assert_close(srgb::from_luma(gray.luma()), gray, 1e-5);
+    assert_close(srgb::from_luma(0.5), [0.735; 3], 1e-3);
+
+    let color = srgb::from([0.2, 0.7, 0.4]);
+    assert_eq!(srgb::from_luma(color.luma()), color.to_grayscale());
+    assert!((color.to_grayscale().luma() - color.luma()).abs() < 1e-5);
+}
+
+#[test]
+fn hdr_tonemapping() {
+    let bright = rgb::from([6.0, 3.0, 1.5]);
+    for op in [ToneMap::Reinhard, ToneMap::AcesFilmic] {
+        let mapped = bright.tonemap(op);
+        assert!(mapped.r < 1.0 && mapped.g < 1.0 && mapped.b < 1.0);
+        assert!(mapped.r >= mapped.g && mapped.g >= mapped.b);
+
+        assert_eq!(rgb::from([0.0; 3]).tonemap(op), srgb::BLACK);
+        assert!(rgb::from([1e6, 50.0, 8.0]).tonemap(op).is_in_gamut());
+    }
+
+    let dark = rgb::from([0.01, 0.02, 0.005]);
+    assert_close(rgb::from(dark.tonemap(ToneMap::Reinhard)), dark, 5e-4);
+}
+
+#[test]
+fn desaturate_highlights() {
+    let hot = rgb {
+        r: 2.0,
+        g: 0.5,
+        b: 0.0,
+    };
+    let mapped = hot.desaturate_to_gamut();
+    assert!(mapped.is_in_gamut());
+    assert!((mapped.relative_luminance() - 0.7828).abs() < 1e-4);
+    // only the channel that overflowed reaches the edge, the others keep their order
+    assert!((mapped.r - 1.0).abs() < 1e-5);
+    assert!(mapped.g > mapped.b && mapped.b > 0.0);
+
+    let fits = rgb::from(srgb::from([0.3, 0.6, 0.9]));
+    assert_close(fits.desaturate_to_gamut(), [0.3, 0.6, 0.9], 1e-5);
+    assert_eq!(rgb::from([5.0; 3]).desaturate_to_gamut(), srgb::WHITE);
+}
+
+#[test]
+fn discrete_steps() {
+    let ramp = steps(srgb::BLACK, srgb::WHITE, 3);
+    assert_eq!(ramp.len(), 3);
+    assert_close(ramp[0], srgb::BLACK, 1e-5);
+    assert_close(ramp[2], srgb::WHITE, 1e-5);
+    assert!((oklab::from(ramp[1]).l - 0.5).abs() < 1e-4);
+
+    let ramp: Vec<f32> = steps(srgb::BLACK, srgb::WHITE, 5)
+        .into_iter()
+        .map(|c| oklab::from(c).l)
+        .collect();
+    for pair in ramp.windows(2) {
+        assert!((pair[1] - pair[0] - 0.25).abs() < 1e-4);
+    }
+
+    assert_eq!(steps(srgb::RED, srgb::BLUE, 2).len(), 2);
+    assert_close(steps(srgb::RED, srgb::BLUE, 1)[0], srgb::RED, 1e-5);
+    assert!(steps(srgb::RED, srgb::BLUE, 0).is_empty());
+}
+
+#[test]
+fn perceptual_equality() {
+    let color = srgb::from([0.8, 0.4, 0.2]);
+    let nudged = srgb::from([0.8003, 0.4002, 0.1997]);
+    assert!(color.visually_equal(&nudged, srgb::JUST_NOTICEABLE));
+    assert_ne!(color, nudged);
+
+    let orange = srgb::from_hex("#ff8000").unwrap();
+    assert!(!srgb::RED.visually_equal(&orange, srgb::JUST_NOTICEABLE));
+
+    assert_eq!(color.delta_e_ok(&color), 0.0);
+    assert!((srgb::BLACK.delta_e_ok(&srgb::WHITE) - 1.0).abs() < 1e-3);
+}
+
+#[test]
+fn tuple_conversions() {
+    assert_eq!(srgb::from((1.0, 0.5, 0.25)), srgb::from([1.0, 0.5, 0.25]));
+    assert_eq!(
+        oklch::from((0.7, 0.1, 0.3)),
+        oklch {
+            l: 0.7,
+            c: 0.1,
+            h: 0.3
+        }
+    );
+
+    let (r, g, b) = srgb::RED.into();
+    assert_eq!((r, g, b), (1.0, 0.0, 0.0));
+    let (l, a, b): (f32, f32, f32) = oklab::from(srgb::WHITE).into();
+    assert_close([l, a, b], [1.0, 0.0, 0.0], 1e-4);
+
+    let (r, g, b, a) = srgba::from((0.1, 0.2, 0.3, 0.4)).into();
+    assert_eq!((r, g, b, a), (0.1, 0.2, 0.3, 0.4));
+}
+
+#[test]
+fn compile_time_hex() {
+    const ACCENT: srgb = tinycolors::color!("#3366cc");
+    assert_eq!(srgb::from_hex("#3366cc"), Ok(ACCENT));
+    assert_eq!(tinycolors::color!("fff"), srgb::WHITE);
+    assert_eq!(srgb::from_hex_const("#12345"), None);
+}
+
+#[test]
+fn alpha_rides_along() {
+    let translucent = Alpha::new(srgb::from([0.2, 0.5, 0.8]), 0.37);
+
+    let mut lab: Alpha<oklab> = translucent.convert();
+    assert_eq!(lab.a, 0.37);
+    lab.color.l += 0.1;
+
+    let back: Alpha<srgb> = lab.convert();
+    assert_eq!(back.a, 0.37);
+    assert_close(
+        translucent.convert::<oklab>().convert::<srgb>().color,
+        translucent.color,
+        1e-5,
+    );
+
+    let packed = srgba::from(back);
+    assert_eq!(packed.a, 0.37);
+    assert_eq!(Alpha::<srgb>::from(packed), back);
+}
+
+#[test]
+fn web_safe_snapping() {
+    assert_eq!(srgb::from([0.5; 3]).to_web_safe(), srgb::from([0.6; 3]));
+    assert_eq!(
+        srgb::from([0.09, 0.31, 0.95]).to_web_safe(),
+        srgb::from([0.0, 0.4, 1.0])
+    );
+    assert_eq!(
+        srgb::from([-0.3, 1.4, 0.65]).to_web_safe().to_hex(),
+        "#00ff99"
+    );
+}
+
+#[test]
+fn achromatic_detection() {
+    assert!(srgb::from([0.5; 3]).is_gray(0.01));
+    assert!(srgb::WHITE.is_gray(0.01));
+    assert!(hsl::from(srgb::from([0.3; 3])).is_gray(0.01));
+
+    let faint_blue = srgb::from([0.5, 0.5, 0.56]);
+    assert!(oklch::from(faint_blue).c > 0.01);
+    assert!(!faint_blue.is_gray(0.01));
+    assert!(!okhsv::from(faint_blue).is_gray(0.01));
+}
+
+#[test]
+fn modulate_rgb() {
+    let color = rgb::from([0.8, 0.4, 0.1]);
+    assert_eq!(color * rgb::from([1.0; 3]), color);
+    assert_eq!(color * rgb::from([0.5; 3]), rgb::from([0.4, 0.2, 0.05]));
+    assert_eq!(
+        color * rgb::from([1.0, 0.0, 0.5]),
+        rgb::from([0.8, 0.0, 0.05])
+    );
+}
+
+#[test]
+fn slice_conversions() {
+    let buffer = [0.1, 0.2, 0.3, 0.4, 0.5, 0.6];
+    assert_eq!(
+        srgb::try_from(&buffer[..3]),
+        Ok(srgb::from([0.1, 0.2, 0.3]))
+    );
+    assert_eq!(
+        oklab::try_from(&buffer[3..]),
+        Ok(oklab::from([0.4, 0.5, 0.6]))
+    );
+    assert_eq!(
+        srgb::try_from(&buffer[..2]),
+        Err(ColorError::WrongLength {
+            expected: 3,
+            found: 2
+        })
+    );
+    assert!(srgba::try_from(&buffer[..4]).is_ok());
+    assert!(srgba::try_from(&buffer[..3]).is_err());
+
+    let colors: Result<Vec<hsl>, _> = buffer.chunks(3).map(hsl::try_from).collect();
+    assert_eq!(colors.unwrap().len(), 2);
+}
+
+#[test]
+fn d50_lab() {
+    assert_close(xyz::D65.to_d50(), xyz::D50, 1e-4);
+    assert_close(xyz::from_d50(xyz::D50), xyz::D65, 1e-4);
+
+    let white = xyz::from(srgb::WHITE).to_cielab_d50();
+    assert_close(white, [100.0, 0.0, 0.0], 0.05);
+
+    // red in d50 lab, as icc tools report it
+    let red = xyz::from(srgb::RED).to_cielab_d50();
+    assert_close(red, [54.29, 80.80, 69.89], 0.1);
+
+    let color = srgb::from([0.3, 0.6, 0.2]);
+    let back = srgb::from(xyz::from(color).to_cielab_d50().d50_to_xyz());
+    assert_close(back, color, 1e-4);
+}
+
+#[test]
+fn scaled_lightness() {
+    let gray = srgb::from([0.5; 3]);
+    let dimmed = gray.scale_lightness(0.5);
+    assert!((oklab::from(dimmed).l - oklab::from(gray).l * 0.5).abs() < 1e-4);
+    assert!(dimmed.r < gray.r);
+
+    assert_close(srgb::BLACK.scale_lightness(0.8), srgb::BLACK, 1e-6);
+    assert_close(gray.scale_lightness(0.0), srgb::BLACK, 1e-6);
+    let color = srgb::from([0.7, 0.3, 0.5]);
+    assert_close(color.scale_lightness(1.0), color, 1e-4);
+    assert_close(color.scale_lightness(5.0), srgb::WHITE, 1e-6);
+}
+
+#[test]
+fn css_color_function() {
+    let red = parse_css_color("color(srgb 1 0 0)").unwrap();
+    assert_eq!(red.color, CssColor::Srgb(srgb::RED));
+    assert_eq!(red.a, 1.0);
+
+    let translucent = parse_css_color("color(srgb 100% 50% none / 25%)").unwrap();
+    assert_eq!(
+        translucent.color,
+        CssColor::Srgb(srgb::from([1.0, 0.5, 0.0]))
+    );
+    assert_eq!(translucent.a, 0.25);
+
+    let linear = parse_css_color("color(srgb-linear 0.5 0.5 0.5 / 0.5)").unwrap();
+    assert_close(linear.color.to_srgb(), [0.735; 3], 1e-3);
+
+    let lab = parse_css_color("color(oklab 0.5 -0.1 0.2)").unwrap();
+    assert_eq!(lab.color, CssColor::Oklab(oklab::from([0.5, -0.1, 0.2])));
+
+    let white = parse_css_color("color(xyz-d50 0.96422 1 0.82521)").unwrap();
+    assert_close(white.color.to_srgb(), srgb::WHITE, 1e-3);
+
+    assert_eq!(
+        parse_css_color("color(display-p3 1 0 0)"),
+        Err(ColorError::UnsupportedSpace)
+    );
+    for bad in [
+        "color(srgb 1 0)",
+        "color(lab 1 0 0)",
+        "rgb(1 0 0)",
+        "color(srgb 1 0 x)",
+    ] {
+        assert_eq!(parse_css_color(bad), Err(ColorError::InvalidCss));
+    }
+}
+
+#[test]
+fn css_color_output() {
+    assert_eq!(
+        srgb::from([1.0, 0.5, 0.25]).to_css_color(),
+        "color(srgb 1 0.5 0.25)"
+    );
+    assert_eq!(
+        srgb::from([1.0 / 3.0, 0.0, -0.00001]).to_css_color(),
+        "color(srgb 0.3333 0 0)"
+    );
+    assert_eq!(
+        oklab::from([0.7, -0.1, 0.05]).to_css_color(),
+        "oklab(0.7 -0.1 0.05)"
+    );
+    assert_eq!(
+        rgb::from([0.5, 0.25, 1.0]).to_css_color(),
+        "color(srgb-linear 0.5 0.25 1)"
+    );
+
+    let parsed = parse_css_color("color(srgb 0.2 0.4 0.6)").unwrap();
+    assert_eq!(parsed.color.to_css_color(), "color(srgb 0.2 0.4 0.6)");
+}
+
+#[cfg(feature = "fixed")]
+#[test]
+fn fixed_point_colors() {
+    use tinycolors::QColor;
+
+    let fixed = QColor::from(srgb::from([1.0, 0.5, 0.3]));
+    assert_eq!(
+        fixed,
+        QColor {
+            r: 256,
+            g: 128,
+            b: 77
+        }
+    );
+    // pinned bytes, so any platform producing something else fails here
+    assert_eq!(fixed.to_le_bytes(), [0x00, 0x01, 0x80, 0x00, 0x4d, 0x00]);
+    assert_eq!(QColor::from_le_bytes(fixed.to_le_bytes()), fixed);
+
+    let back = srgb::from(fixed);
+    assert_eq!(QColor::from(back), fixed);
+    assert_close(back, [1.0, 0.5, 0.3], 0.5 / 256.0);
+
+    assert_eq!(
+        QColor::from(srgb::from([-0.5, 200.0, f32::NAN])),
+        QColor {
+            r: -128,
+            g: i16::MAX,
+            b: 0
+        }
+    );
+}
+
+#[test]
+fn gradient_projection() {
+    let gradient = Gradient::evenly_spaced([srgb::BLACK, srgb::WHITE].map(oklab::from));
+    let mid = srgb::from(gradient.sample(0.5));
+    assert!((gradient.project(mid) - 0.5).abs() < 1e-3);
+
+    let heat = Gradient::evenly_spaced([srgb::BLUE, srgb::GREEN, srgb::RED].map(rgb::from));
+    for t in [0.0, 0.2, 0.65, 1.0] {
+        let color = srgb::from(heat.sample(t));
+        assert!((heat.project(color) - t).abs() < 1e-3);
+    }
+
+    // colors off the gradient land on the closest point
+    assert!(gradient.project(srgb::from([1.0, 1.0, 0.9])) > 0.9);
+}
+
+#[test]
+fn palette_lightness_range() {
+    let palette = [
+        srgb::from([0.3, 0.1, 0.1]),
+        srgb::from([0.2, 0.5, 0.3]),
+        srgb::from([0.6, 0.6, 0.9]),
+    ];
+    let normalized = normalize_lightness(&palette, 0.3, 0.8);
+    let lightness: Vec<f32> = normalized.iter().map(|&c| oklab::from(c).l).collect();
+
+    let min = lightness.iter().copied().fold(f32::INFINITY, f32::min);
+    let max = lightness.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    assert!((min - 0.3).abs() < 1e-3);
+    assert!((max - 0.8).abs() < 1e-3);
+
+    // hue survives
+    for (before, after) in palette.iter().zip(&normalized) {
+        assert!((oklch::from(*before).h - oklch::from(*after).h).abs() < 1e-2);
+    }
+
+    let flat = normalize_lightness(&[srgb::from([0.5; 3]); 2], 0.2, 0.6);
+    assert!((oklab::from(flat[0]).l - 0.4).abs() < 1e-3);
+    assert!(normalize_lightness(&[], 0.0, 1.0).is_empty());
+}
+
+#[cfg(feature = "csscolorparser")]
+#[test]
+fn csscolorparser_interop() {
+    let parsed = csscolorparser::parse("rgb(255 128 64 / 50%)").unwrap();
+    let color = srgba::from(parsed);
+    assert_close(color.rgb(), [1.0, 128.0 / 255.0, 64.0 / 255.0], 1e-6);
+    assert_eq!(color.a, 0.5);
+
+    assert_eq!(csscolorparser::Color::from(color), parsed);
+}
+
+#[test]
+fn in_place_adjustments() {
+    let color = srgb::from([0.2, 0.4, 0.7]);
+
+    let mut pixels = vec![color; 3];
+    for pixel in &mut pixels {
+        pixel.lighten_mut(0.1);
+    }
+    assert!(pixels.iter().all(|&p| p == color.lighten(0.1)));
+
+    let mut edited = color;
+    edited.rotate_hue_mut(0.25);
+    edited.desaturate_mut(0.5);
+    assert_eq!(edited, color.rotate_hue(0.25).desaturate(0.5));
+
+    let mut wild = srgb::from([1.5, f32::NAN, -0.2]);
+    wild.sanitize_mut();
+    wild.clamp_mut(0.0, 1.0);
+    assert_eq!(wild, srgb::from([1.0, 0.0, 0.0]));
+}
+
+#[test]
+fn barycentric_blend() {
+    let (a, b, c) = (
+        rgb::from([0.9, 0.0, 0.0]),
+        rgb::from([0.0, 0.6, 0.0]),
+        rgb::from([0.0, 0.0, 0.3]),
+    );
+    assert_close(blend3(a, b, c, 1.0, 1.0, 1.0), [0.3, 0.2, 0.1], 1e-6);
+    assert_close(blend3(a, b, c, 2.0, 2.0, 2.0), [0.3, 0.2, 0.1], 1e-6);
+    assert_eq!(blend3(a, b, c, 0.0, 1.0, 0.0), b);
+    assert_eq!(blend3(a, b, c, 0.0, 0.0, 0.0), rgb::default());
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn parallel_conversion() {
+    let pixels: Vec<srgb> = (0..10_000)
+        .map(|i| srgb::from([(i % 256) as f32 / 255.0, (i / 256) as f32 / 40.0, 0.5]))
+        .collect();
+
+    let parallel: Vec<oklab> = tinycolors::par_convert(&pixels);
+    let sequential: Vec<oklab> = pixels.iter().map(|&p| p.into()).collect();
+    assert_eq!(parallel, sequential);
+}
+
+#[test]
+fn shortest_hue_distance() {
+    assert!((hue_distance(0.95, 0.05) - 0.1).abs() < 1e-6);
+    assert!((hue_distance(0.05, 0.95) - 0.1).abs() < 1e-6);
+    assert!((hue_distance(0.2, 0.7) - 0.5).abs() < 1e-6);
+    assert!((hue_distance(1.3, -0.1) - 0.4).abs() < 1e-6);
+    assert_eq!(hue_distance(0.4, 0.4), 0.0);
+}
+
+#[test]
+fn okhsl_okhsv_path_error() {
+    // grays used to come back lighter, since the okhsl crate skips the toe for them on the way in
+    for gray in [0.1, 0.5, 0.6875, 0.9] {
+        let color = srgb::from([gray; 3]);
+        assert_close(srgb::from(okhsl::from(color)), color, 1e-5);
+        assert_close(srgb::from(okhsv::from(color)), color, 1e-5);
+        assert_close(
+            rgb::from(okhsl::from(rgb::from(color))),
+            rgb::from(color),
+            1e-5,
+        );
+    }
+
+    // paths through srgb and oklab stack up little error
+    for color in [
+        srgb::from([0.8, 0.3, 0.5]),
+        srgb::from([0.1, 0.7, 0.2]),
+        srgb::from([0.95, 0.9, 0.1]),
+    ] {
+        let via_hsv = okhsl::from(hsv::from(color));
+        assert_close(via_hsv, okhsl::from(color), 1e-4);
+        assert_close(hsv::from(via_hsv), hsv::from(color), 1e-4);
+        assert_close(srgb::from(okhsv::from(okhsl::from(color))), color, 1e-4);
+        assert_close(
+            xyz::from(okhsv::from(xyz::from(color))),
+            xyz::from(color),
+            1e-4,
+        );
+    }
+}
+
+#[test]
+fn srgba_byte_orders() {
+    let red = srgba::with_alpha(srgb::RED, 1.0);
+    assert_eq!(red.to_rgba_bytes(), [255, 0, 0, 255]);
+    assert_eq!(red.to_bgra_bytes(), [0, 0, 255, 255]);
+
+    let color = srgba::from([0.2, 0.4, 1.5, 0.5]);
+    assert_eq!(color.to_rgba_bytes(), [51, 102, 255, 128]);
+    assert_eq!(color.to_bgra_bytes(), [255, 102, 51, 128]);
+}
+
+#[test]
+fn perceptual_palette_order() {
+    let rainbow: Vec<srgb> = hue_wheel(8, 0.7, 0.1).collect();
+    let mut shuffled = [
+        rainbow[5],
+        srgb::WHITE,
+        rainbow[2],
+        rainbow[7],
+        rainbow[0],
+        srgb::from([0.3; 3]),
+        rainbow[4],
+        rainbow[1],
+        rainbow[6],
+        rainbow[3],
+    ];
+    sort_perceptual(&mut shuffled);
+
+    assert_eq!(shuffled[0], srgb::from([0.3; 3]));
+    assert_eq!(shuffled[1], srgb::WHITE);
+    assert_eq!(shuffled[2..], rainbow[..]);
+}
+
+#[test]
+fn nearest_tailwind_swatch() {
+    assert_eq!(srgb::BLUE.nearest_tailwind().0, "blue");
+    assert_eq!(
+        srgb::from_hex("#3b82f6").unwrap().nearest_tailwind(),
+        ("blue", 500)
+    );
+    assert_eq!(srgb::WHITE.nearest_tailwind().1, 50);
+}
+
+#[test]
+fn diverging_gradient() {
+    let (low, mid, high) = (srgb::BLUE, srgb::WHITE, srgb::RED);
+    let scale = diverging(low, mid, high);
+
+    assert_eq!(scale.stops().len(), 3);
+    assert_close(srgb::from(scale.sample(0.5)), mid, 1e-5);
+    assert!(srgb::from(scale.sample(0.0)).visually_equal(&low, srgb::JUST_NOTICEABLE));
+    assert!(srgb::from(scale.sample(1.0)).visually_equal(&high, srgb::JUST_NOTICEABLE));
+}
+
+#[test]
+fn xyy_round_trip() {
+    let color = xyz::from(srgb::from([0.8, 0.4, 0.2]));
+    let chromaticity = xyy::from(color);
+    assert_eq!(chromaticity.Y, color.y);
+    assert_close(xyz::from(chromaticity), color, 1e-6);
+
+    let white = xyy::from(xyz::D65);
+    assert_close([white.x, white.y, 0.0], [0.3127, 0.3290, 0.0], 1e-4);
+
+    let black = xyy::from(xyz::default());
+    assert_eq!((black.x, black.y), xyy::D65_CHROMATICITY);
+    assert_eq!(xyz::from(black), xyz::default());
+}
+
+#[test]
+fn palette_gamut_volume() {
+    assert_eq!(gamut_volume(&[]), 0.0);
+    assert_eq!(gamut_volume(&[srgb::RED; 5]), 0.0);
+
+    let grays: Vec<srgb> = (0..5).map(|i| srgb::from([i as f32 / 4.0; 3])).collect();
+    assert!(gamut_volume(&grays) < 1e-6);
+
+    let mut rainbow: Vec<srgb> = hue_wheel(12, 0.7, 0.1).collect();
+    rainbow.extend([srgb::BLACK, srgb::WHITE]);
+    let rainbow_volume = gamut_volume(&rainbow);
+    assert!(rainbow_volume > 1e-3);
+
+    let corners: Vec<srgb> = (0..8)
+        .map(|i| srgb::from([i & 1, i >> 1 & 1, i >> 2 & 1].map(|c| c as f32)))
+        .collect();
+    let cube = gamut_volume(&corners);
+    assert!(cube > rainbow_volume);
+
+    // colors inside the hull don't add to it
+    let mut filled = corners.clone();
+    filled.extend(rainbow);
+    filled.extend(grays);
+    assert!((gamut_volume(&filled) - cube).abs() < 1e-6);
+}
+
+#[test]
+fn soft_clip_highlights() {
+    let knee = 0.8;
+    let color = srgb::from([0.5, 0.8, -0.1]);
+    assert_eq!(color.soft_clip(knee), color);
+
+    let bright = srgb::from([1.0, 2.0, 20.0]).soft_clip(knee);
+    assert!(bright.r > knee && bright.r < bright.g && bright.g < bright.b);
+    assert!(bright.b <= 1.0 && bright.b > 0.999);
+
+    // monotonic across the knee
+    let mut last = f32::NEG_INFINITY;
+    for i in 0..=100 {
+        let c = srgb::from([i as f32 / 25.0; 3]).soft_clip(knee).r;
+        assert!(c >= last && c <= 1.0);
+        last = c;
+    }
+
+    assert_eq!(
+        srgb::from([1.5, 0.5, 1.0]).soft_clip(1.0),
+        srgb::from([1.0, 0.5, 1.0])
+    );
+}
+
+#[test]
+fn clipping_channel_flags() {
+    let color = srgb {
+        r: 1.2,
+        g: 0.5,
+        b: -0.1,
+    };
+    assert_eq!(color.clipping_channels(), [true, false, true]);
+    assert_eq!(srgb::WHITE.clipping_channels(), [false; 3]);
+    assert_eq!(
+        srgb::from([1.0 + 1e-6, -1e-6, 0.0]).clipping_channels(),
+        [false; 3]
+    );
+}
+
+#[test]
+fn const_gray() {
+    const MID: srgb = srgb::gray(0.5);
+    const LINEAR_MID: rgb = rgb::gray(0.5);
+
+    assert_eq!(MID, srgb::from([0.5; 3]));
+    assert_eq!(LINEAR_MID, rgb::from([0.5; 3]));
+    assert!(MID.is_gray(1e-6));
+}
+
+#[test]
+fn temperature_and_tint() {
+    let candle = srgb::from_kelvin(1900.0);
+    assert!(candle.r > candle.g && candle.g > candle.b);
+    let sky = srgb::from_kelvin(12000.0);
+    assert!(sky.b > sky.r);
+    let daylight = srgb::from_kelvin(6504.0);
+    assert!(daylight.visually_equal(&srgb::WHITE, 0.05));
+
+    for kelvin in [2700.0, 5000.0, 9000.0] {
+        assert_eq!(srgb::from_temp_tint(kelvin, 0.0), srgb::from_kelvin(kelvin));
+
+        let neutral = rgb::from(srgb::from_kelvin(kelvin));
+        let green = rgb::from(srgb::from_temp_tint(kelvin, 0.01));
+        let magenta = rgb::from(srgb::from_temp_tint(kelvin, -0.01));
+        assert!(green.g / (green.r + green.b) > neutral.g / (neutral.r + neutral.b));
+        assert!(magenta.g / (magenta.r + magenta.b) < neutral.g / (neutral.r + neutral.b));
+    }
+}
+
+#[test]
+fn generic_lerp() {
+    fn halfway<T: Color>(a: T, b: T) -> T {
+        a.lerp(b, 0.5)
+    }
+
+    let mid = halfway(rgb::from([0.0, 0.2, 1.0]), rgb::from([1.0, 0.4, 0.0]));
+    assert_close(mid, [0.5, 0.3, 0.5], 1e-6);
+    assert_eq!(srgb::BLACK.lerp(srgb::WHITE, 0.0), srgb::BLACK);
+    assert_eq!(srgb::BLACK.lerp(srgb::WHITE, 1.0), srgb::WHITE);
+
+    // hues take the short way around instead of passing through green and blue
+    let red = hsl::from([0.95, 1.0, 0.5]);
+    let orange = hsl::from([0.05, 1.0, 0.5]);
+    assert_close(halfway(red, orange), [0.0, 1.0, 0.5], 1e-6);
+    let purple = oklch::from([0.6, 0.1, 0.9]);
+    let yellow = oklch::from([0.8, 0.2, 0.3]);
+    assert_close(halfway(purple, yellow), [0.7, 0.15, 0.1], 1e-6);
+    assert_close(purple.lerp(yellow, 0.25), [0.65, 0.125, 0.0], 1e-6);
+}
+
+#[test]
+fn gimp_palette_parsing() {
+    let text = "GIMP Palette
+Name: sunset
+Columns: 2
+# exported from krita
+255   0   0\tRed
+  0 128 255\tsky blue
+
+ 12  34  56
+";
+    let palette = parse_gpl(text).unwrap();
+    assert_eq!(palette.len(), 3);
+    assert_eq!(palette[0], ("Red".to_string(), srgb::RED));
+    assert_eq!(palette[1].0, "sky blue");
+    assert_eq!(palette[1].1.to_hex(), "#0080ff");
+    assert_eq!(palette[2].0, "");
+    assert_eq!(palette[2].1.to_hex(), "#0c2238");
+
+    assert_eq!(parse_gpl("0 0 0 black"), Err(GplError::MissingHeader));
+    assert_eq!(
+        parse_gpl("GIMP Palette\n0 0 0 black\n256 0 0 too bright"),
+        Err(GplError::InvalidLine { line: 3 })
+    );
+}
+
+#[test]
+fn gimp_palette_round_trip() {
+    let palette = vec![
+        ("black".to_string(), srgb::BLACK),
+        ("warm white".to_string(), srgb::from_hex("#fff4e0").unwrap()),
+        (String::new(), srgb::from_hex("#336699").unwrap()),
+    ];
+
+    let text = to_gpl("test\npalette", &palette);
+    assert!(text.starts_with("GIMP Palette\nName: test palette\n"));
+    assert!(text.contains("255 244 224\twarm white\n"));
+    assert_eq!(parse_gpl(&text), Ok(palette));
+}
+
+#[test]
+fn adobe_swatch_exchange() {
+    fn color_block(name: &str, model: &[u8; 4], channels: &[f32]) -> Vec<u8> {
+        let mut data = Vec::new();
+        let units: Vec<u16> = name.encode_utf16().chain([0]).collect();
+        data.extend((units.len() as u16).to_be_bytes());
+        data.extend(units.iter().flat_map(|u| u.to_be_bytes()));
+        data.extend(model);
+        data.extend(channels.iter().flat_map(|c| c.to_be_bytes()));
+        // a global swatch
+        data.extend(0u16.to_be_bytes());
+
+        let mut block = vec![0x00, 0x01];
+        block.extend((data.len() as u32).to_be_bytes());
+        block.extend(data);
+        block
+    }
+
+    let mut file = b"ASEF\x00\x01\x00\x00".to_vec();
+    file.extend(1u32.to_be_bytes());
+    file.extend(color_block("Brand Ø", b"RGB ", &[1.0, 0.5, 0.0]));
+    assert_eq!(
+        parse_ase(&file),
+        Ok(vec![("Brand Ø".to_string(), srgb::from([1.0, 0.5, 0.0]))])
+    );
+
+    let mut file = b"ASEF\x00\x01\x00\x00".to_vec();
+    file.extend(2u32.to_be_bytes());
+    file.extend(color_block("ink", b"CMYK", &[0.0, 1.0, 1.0, 0.0]));
+    file.extend(color_block("mid", b"Gray", &[0.5]));
+    let swatches = parse_ase(&file).unwrap();
+    assert_eq!(swatches[0].1, srgb::RED);
+    assert_eq!(swatches[1].1, srgb::gray(0.5));
+
+    assert_eq!(parse_ase(b"RIFF"), Err(AseError::InvalidSignature));
+    assert_eq!(parse_ase(&file[..file.len() - 3]), Err(AseError::Truncated));
+
+    assert_eq!(cmyk::from(srgb::RED), cmyk::from([0.0, 1.0, 1.0, 0.0]));
+    assert_eq!(cmyk::from(srgb::BLACK), cmyk::from([0.0, 0.0, 0.0, 1.0]));
+}
+
+#[test]
+fn android_style_swatches() {
+    let red = srgb::from([0.9, 0.1, 0.15]);
+    let mut pixels = vec![red; 300];
+    pixels.extend(vec![srgb::from([0.45, 0.5, 0.55]); 500]);
+    pixels.extend(vec![srgb::from([0.1, 0.1, 0.12]); 200]);
+
+    let swatches = palette_swatches(&pixels);
+    let vibrant = swatches.vibrant.expect("the red region should be vibrant");
+    assert!(vibrant.visually_equal(&red, srgb::JUST_NOTICEABLE));
+    assert!(swatches.muted.unwrap().is_gray(0.05));
+    assert!(swatches.dark_muted.is_some());
+    assert_eq!(swatches.light_vibrant, None);
+
+    assert_eq!(palette_swatches(&[]), Default::default());
+}
+
+#[test]
+fn hue_preserving_clamp() {
+    for color in [
+        oklch::from([0.7, 0.4, 0.8]),
+        oklch::from([0.95, 0.3, 0.3]),
+        oklch::from([0.2, 0.3, 0.05]),
+    ] {
+        let clamped = color.clamp_preserve_hue();
+        assert!(clamped.is_in_gamut());
+        let result = oklch::from(clamped);
+        assert!(hue_distance(result.h, color.h) < 1e-3);
+
+        // it's never further away than plain chroma reduction
+        let reduced = oklch::from(color.gamut_map());
+        let distance = |c: oklch| (c.l - color.l).hypot(c.c - color.c);
+        assert!(distance(result) <= distance(reduced) + 1e-4);
+    }
+
+    let inside = srgb::from([0.2, 0.4, 0.6]);
+    assert_close(inside.clamp_preserve_hue(), inside, 1e-5);
+}
+
+#[test]
+fn accessible_contrast_pair() {
+    let brand = srgb::from_hex("#3b82f6").unwrap();
+    for min_contrast in [3.0, 4.5, 5.5] {
+        let (seed, partner) = accessible_pair(brand, min_contrast);
+        assert_eq!(seed, brand);
+        assert!(partner.contrast_ratio(&seed) >= min_contrast);
+        // only as far as needed
+        assert!(partner.contrast_ratio(&seed) < min_contrast + 0.1);
+        assert!(hue_distance(oklch::from(partner).h, oklch::from(brand).h) < 0.01);
+    }
+
+    let (_, partner) = accessible_pair(srgb::gray(0.5), 30.0);
+    assert_eq!(partner, srgb::BLACK);
+}
+
+#[test]
+fn per_channel_curve() {
+    let color = srgb::from([0.1, 0.55, 0.9]);
+
+    let identity: Vec<f32> = (0..=16).map(|i| i as f32 / 16.0).collect();
+    assert_close(color.apply_curve(&identity), color, 1e-6);
+    assert_close(color.apply_curve(&[0.0, 1.0]), color, 1e-6);
+
+    let inverted = color.apply_curve(&[1.0, 0.0]);
+    assert_close(inverted, [0.9, 0.45, 0.1], 1e-6);
+
+    // a three point curve interpolates between its entries
+    let lifted = srgb::from([0.25, 0.5, 2.0]).apply_curve(&[0.2, 0.6, 1.0]);
+    assert_close(lifted, [0.4, 0.6, 1.0], 1e-6);
+
+    assert_eq!(color.apply_curve(&[]), color);
+    assert_eq!(color.apply_curve(&[0.3]), srgb::gray(0.3));
+}
+
+#[test]
+fn cube_lut_loading() {
+    let mut text = String::from(
+        "TITLE \"identity\"\n# made by hand\nLUT_3D_SIZE 3\nDOMAIN_MIN 0.0 0.0 0.0\nDOMAIN_MAX 1.0 1.0 1.0\n\n",
+    );
+    for b in 0..3 {
+        for g in 0..3 {
+            for r in 0..3 {
+                text += &format!("{} {} {}\n", r as f32 / 2.0, g as f32 / 2.0, b as f32 / 2.0);
+            }
+        }
+    }
+
+    let lut = parse_cube(&text).unwrap();
+    assert_eq!(lut.size(), 3);
+    for color in [srgb::RED, srgb::from([0.2, 0.7, 0.45]), srgb::gray(0.5)] {
+        assert_close(lut.apply::<srgb>(color), color, 1e-6);
+    }
+
+    // a wider domain squeezes the input range
+    let wide =
+        parse_cube(&text.replace("DOMAIN_MAX 1.0 1.0 1.0", "DOMAIN_MAX 2.0 2.0 2.0")).unwrap();
+    assert_close(
+        wide.apply::<srgb>(srgb::from([1.0, 0.5, 2.0])),
+        [0.5, 0.25, 1.0],
+        1e-6,
+    );
+
+    assert_eq!(parse_cube("0 0 0\n"), Err(CubeError::MissingSize));
+    assert_eq!(
+        parse_cube("LUT_1D_SIZE 2\n0 0 0\n1 1 1\n"),
+        Err(CubeError::OneDimensional)
+    );
+    assert_eq!(
+        parse_cube("LUT_3D_SIZE 2\n0 0 0\n"),
+        Err(CubeError::WrongLength {
+            expected: 8,
+            found: 1
+        })
+    );
+    assert_eq!(
+        parse_cube("LUT_3D_SIZE 2\n0 0\n"),
+        Err(CubeError::InvalidLine { line: 2 })
+    );
+}
+
+#[test]
+fn ink_coverage() {
+    assert!((srgb::BLACK.ink_coverage() - 1.0).abs() < 1e-6);
+    assert_eq!(srgb::WHITE.ink_coverage(), 0.0);
+    assert!((srgb::RED.ink_coverage() - 2.0).abs() < 1e-6);
+
+    let dark_red = srgb::from([0.1, 0.0, 0.0]);
+    assert!(dark_red.ink_coverage() > 2.5 && dark_red.ink_coverage() <= 3.0);
+}
+
+#[test]
+fn explicit_rounding_modes() {
+    let color = srgb::from([0.999, 0.5, 0.0]);
+    assert_eq!(color.to_u8_with(RoundMode::Nearest), [255, 128, 0]);
+    assert_eq!(color.to_u8_with(RoundMode::Floor), [254, 127, 0]);
+    assert_eq!(color.to_u8_with(RoundMode::Ceil), [255, 128, 0]);
+    assert_eq!(srgb::WHITE.to_u8_with(RoundMode::Stochastic(7)), [255; 3]);
+
+    // stochastic rounding stays on the two neighbouring bytes and averages out to the exact value over different seeds
+    let value = 100.3 / 255.0;
+    let samples: Vec<u8> = (0..2000)
+        .map(|seed| srgb::gray(value).to_u8_with(RoundMode::Stochastic(seed))[0])
+        .collect();
+    assert!(samples.iter().all(|&b| b == 100 || b == 101));
+    let mean = samples.iter().map(|&b| b as f32).sum::<f32>() / samples.len() as f32;
+    assert!((mean - 100.3).abs() < 0.05);
+
+    // and the same seed always rounds the same way
+    let color = srgb::from([0.2, 0.5, 0.7]);
+    assert_eq!(
+        color.to_u8_with(RoundMode::Stochastic(42)),
+        color.to_u8_with(RoundMode::Stochastic(42))
+    );
+}
+
+#[cfg(feature = "approx")]
+#[test]
+fn approx_comparisons() {
+    use approx::{assert_abs_diff_eq, assert_relative_eq, assert_relative_ne};
+
+    let color = srgb::from([0.8, 0.4, 0.2]);
+    let round_tripped = srgb::from(oklab::from(color));
+    assert_abs_diff_eq!(round_tripped, color, epsilon = 1e-5);
+    assert_relative_eq!(
+        hsl::from(srgb::from(hsl::from(color))),
+        hsl::from(color),
+        epsilon = 1e-5
+    );
+    assert_relative_ne!(color, srgb::from([0.8, 0.4, 0.21]), epsilon = 1e-5);
+
+    let translucent = srgba::with_alpha(color, 0.5);
+    assert_abs_diff_eq!(
+        translucent,
+        srgba::with_alpha(round_tripped, 0.5),
+        epsilon = 1e-5
+    );
+}
+
+#[test]
+fn eased_gradient() {
+    let gradient = Gradient::new([(0.0, rgb::gray(0.0)), (1.0, rgb::gray(1.0))]);
+    let eased = gradient.clone().with_easing(smoothstep);
+
+    let h = 1e-3;
+    assert!(eased.sample(h).r - eased.sample(0.0).r < 1e-5);
+    assert!(eased.sample(1.0).r - eased.sample(1.0 - h).r < 1e-5);
+    assert!(gradient.sample(h).r - gradient.sample(0.0).r > 1e-4);
+
+    assert_eq!(eased.sample(0.5), gradient.sample(0.5));
+    assert_close(eased.sample(0.25), rgb::gray(smoothstep(0.25)), 1e-6);
+    assert!((eased.project(eased.sample(0.3).into()) - 0.3).abs() < 1e-3);
+
+    let custom = gradient.clone().with_easing(|t| t * t);
+    assert_close(custom.sample(0.5), rgb::gray(0.25), 1e-6);
+    assert_eq!(eased, eased.clone());
+    assert_eq!(custom, custom.clone());
+    assert_ne!(eased, gradient);
+}
+
+#[test]
+fn transfer_curve_agreement() {
+    // the runtime curve and the const one behind the lut share their parameters, so they agree to within rounding of the input across the whole byte range
+    for byte in 0..=255u8 {
+        let lut = rgb::from_u8_linear([byte; 3]).r;
+        let runtime = rgb::from(srgb::gray(byte as f32 / 255.0)).r;
+        assert!(
+            (lut - runtime).abs() <= 4.0 * f32::EPSILON * runtime,
+            "byte {byte}: {lut} != {runtime}"
+        );
+    }
+
+    // encoding and decoding invert each other, including around the knee near black
+    for i in 0..=4096 {
+        let x = i as f32 / 4096.0;
+        let decoded = rgb::from(srgb::gray(x));
+        assert_close(srgb::from(decoded), srgb::gray(x), 1e-6);
+    }
+    let below = rgb::from(srgb::gray(0.04045 - 1e-6)).r;
+    let above = rgb::from(srgb::gray(0.04045 + 1e-6)).r;
+    assert!((above - below).abs() < 1e-6);
+}
+
+#[test]
+fn turbofish_convert() {
+    assert_eq!(srgb::RED.convert::<hsl>(), hsl::from(srgb::RED));
+    assert_eq!(
+        srgb::RED.convert::<oklab>().convert::<xyz>(),
+        xyz::from(oklab::from(srgb::RED))
+    );
+}
+
+#[test]
+fn gradient_nan_position() {
+    let gradient = Gradient::evenly_spaced([srgb::RED, srgb::WHITE, srgb::BLUE].map(oklab::from));
+    assert_eq!(gradient.sample(f32::NAN), oklab::from(srgb::RED));
+    assert_eq!(gradient.sample_smooth(f32::NAN), oklab::from(srgb::RED));
+}
+
+#[test]
+fn hsi_to_and_from_every_space() {
+    let color = hsi::from(srgb::from([0.8, 0.4, 0.2]));
+
+    assert_close(xyz::from(color), xyz::from(srgb::from(color)), 1e-6);
+    assert_close(cielab::from(color), cielab::from(srgb::from(color)), 1e-4);
+    assert_close(cielch::from(color), cielch::from(srgb::from(color)), 1e-4);
+
+    assert_close(hsi::from(xyz::from(color)), color, 1e-5);
+    assert_close(hsi::from(cielab::from(color)), color, 1e-5);
+    assert_close(hsi::from(cielch::from(color)), color, 1e-5);
+}
+
+#[test]
+fn oklch_to_and_from_every_space() {
+    let color = oklch::from(srgb::from([0.2, 0.5, 0.7]));
+
+    assert_close(xyz::from(color), xyz::from(oklab::from(color)), 1e-6);
+    assert_close(cielab::from(color), cielab::from(oklab::from(color)), 1e-4);
+    assert_close(cielch::from(color), cielch::from(oklab::from(color)), 1e-4);
+    assert_close(hsi::from(color), hsi::from(srgb::from(color)), 1e-6);
+
+    assert_close(oklch::from(xyz::from(color)), color, 1e-5);
+    assert_close(oklch::from(cielab::from(color)), color, 1e-5);
+    assert_close(oklch::from(cielch::from(color)), color, 1e-5);
+    assert_close(oklch::from(hsi::from(color)), color, 1e-5);
+}
+
+#[test]
+fn xyy_to_and_from_every_space() {
+    let color = xyy::from(srgb::from([0.2, 0.5, 0.7]));
+
+    assert_close(cielab::from(color), cielab::from(xyz::from(color)), 1e-5);
+    assert_close(cielch::from(color), cielch::from(xyz::from(color)), 1e-5);
+    assert_close(hsi::from(color), hsi::from(xyz::from(color)), 1e-6);
+    assert_close(oklch::from(color), oklch::from(xyz::from(color)), 1e-6);
+
+    assert_close(xyy::from(cielab::from(color)), color, 1e-5);
+    assert_close(xyy::from(cielch::from(color)), color, 1e-5);
+    assert_close(xyy::from(hsi::from(color)), color, 1e-5);
+    assert_close(xyy::from(oklch::from(color)), color, 1e-5);
+}
+
+#[test]
+fn inherent_conversions_match_from() {
+    macro_rules! check {
+        ($($color:ident),*) => {$(
+            let color = $color::from(srgb { r: 0.9, g: 0.4, b: 0.1 });
+            assert_eq!(color.to_srgb(), srgb::from(color));
+            assert_eq!(color.to_rgb(), rgb::from(color));
+            assert_eq!(color.to_oklab(), oklab::from(color));
+            assert_eq!(color.to_okhsl(), okhsl::from(color));
+            assert_eq!(color.to_okhsv(), okhsv::from(color));
+            assert_eq!(color.to_hsl(), hsl::from(color));
+            assert_eq!(color.to_hsv(), hsv::from(color));
+            assert_eq!(color.to_xyz(), xyz::from(color));
+            assert_eq!(color.to_cielab(), cielab::from(color));
+            assert_eq!(color.to_cielch(), cielch::from(color));
+            assert_eq!(color.to_hsi(), hsi::from(color));
+            assert_eq!(color.to_oklch(), oklch::from(color));
+            assert_eq!(color.to_xyy(), xyy::from(color));
+        )*};
+    }
+
+    check!(
+        srgb, rgb, oklab, okhsl, okhsv, hsl, hsv, xyz, cielab, cielch, hsi, oklch, xyy
+    );
+}
+
+#[test]
+fn css_color_round_trip() {
+    for color in [
+        CssColor::Srgb(srgb::from([1.0, 0.5, 0.25])),
+        CssColor::SrgbLinear(rgb::from([0.5, 0.25, 1.0])),
+        CssColor::Oklab(oklab::from([0.7, -0.1, 0.05])),
+        CssColor::Xyz(xyz::from([0.25, 0.5, 0.75])),
+    ] {
+        assert_eq!(
+            parse_css_color(&color.to_css_color()),
+            Ok(Alpha::new(color, 1.0))
+        );
+
+        let translucent = Alpha::new(color, 0.25);
+        assert_eq!(
+            parse_css_color(&translucent.to_css_color()),
+            Ok(translucent)
+        );
+    }
+
+    assert_eq!(
+        Alpha::new(CssColor::Oklab(oklab::from([0.7, -0.1, 0.05])), 0.5).to_css_color(),
+        "oklab(0.7 -0.1 0.05 / 0.5)"
+    );
+}